@@ -2,7 +2,7 @@
 use lalrpop;
 
 fn main() {
-    lalrpop::Configuration::new()
-        .generate_in_source_tree()
-        .process();
+    // The parser is generated into `OUT_DIR`, so building this crate as a dependency
+    // never writes into its sources
+    lalrpop::process_root().unwrap();
 }
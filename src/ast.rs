@@ -1,35 +1,216 @@
+use std::fmt;
+
 #[derive(Clone, Debug, std::cmp::PartialEq, std::cmp::Eq)]
 pub enum Type {
     Num,
     None,
     Str,
-    Identifier,
+    Bool,
     Ptr(Box<Type>),
-    Invalid,
-    Block,
+    // Accepts values of every type, for the parameters of functions registered from Rust
+    Any,
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Type::Num => write!(f, "number"),
+            Type::None => write!(f, "none"),
+            Type::Str => write!(f, "string"),
+            Type::Bool => write!(f, "bool"),
+            Type::Ptr(inner) => write!(f, "pointer to {}", inner),
+            Type::Any => write!(f, "any value"),
+        }
+    }
+}
+
+/// Where a node comes from, as byte offsets into the source. The end is not included.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+/// How deeply statements and expressions can be nested inside each other. Cloning and
+/// dropping nodes is recursive, so deeper code could overflow the stack before it runs.
+pub const MAX_NESTING: usize = 500;
+
+#[derive(Clone, Debug)]
+pub struct Node {
+    pub kind: NodeKind,
+    pub span: Span,
+    // How many nodes deep the tree under this one goes, this one included
+    depth: usize,
+}
+
+impl Node {
+    /// Nodes nested deeper than `MAX_NESTING` become `NodeKind::TooDeep`, keeping where
+    /// the nesting went too deep
+    pub fn new(kind: NodeKind, start: usize, end: usize) -> Node {
+        let span = Span { start, end };
+        let depth = kind
+            .children()
+            .iter()
+            .map(|child| child.depth)
+            .max()
+            .unwrap_or(0)
+            + 1;
+        if depth <= MAX_NESTING {
+            return Node { kind, span, depth };
+        }
+
+        let too_deep = kind.children().iter().find_map(|child| match child.kind {
+            NodeKind::TooDeep(span) => Some(span),
+            _ => None,
+        });
+        Node {
+            kind: NodeKind::TooDeep(too_deep.unwrap_or(span)),
+            span,
+            depth,
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
-pub enum Node {
-    Num(String),
+pub enum NodeKind {
+    Num(f64),
+    // Without the quotes and with escape sequences already replaced
     Str(String),
+    Bool(bool),
     Identifier(String),
 
     Add(Box<Node>, Box<Node>),
     Sub(Box<Node>, Box<Node>),
     Mul(Box<Node>, Box<Node>),
     Div(Box<Node>, Box<Node>),
+    Mod(Box<Node>, Box<Node>),
+    Pow(Box<Node>, Box<Node>),
     Eq(Box<Node>, Box<Node>),
     NotEq(Box<Node>, Box<Node>),
     Gt(Box<Node>, Box<Node>),
     Lt(Box<Node>, Box<Node>),
+    GtEq(Box<Node>, Box<Node>),
+    LtEq(Box<Node>, Box<Node>),
+    And(Box<Node>, Box<Node>),
+    Or(Box<Node>, Box<Node>),
+    Not(Box<Node>),
+    Neg(Box<Node>),
+    Pos(Box<Node>),
 
     Assign(Box<Node>, Box<Node>),
+    Let(Box<Node>, Box<Node>),
     Print(Box<Node>),
     Comment(String),
     Block(Vec<Node>),
-    Proc(Box<Node>, Box<Node>),
-    Call(Box<Node>),
+    // Name, parameters and body
+    Proc(Box<Node>, Vec<Node>, Box<Node>),
+    // Name and arguments
+    Call(Box<Node>, Vec<Node>),
+    // Name, parameters and body
+    Fn(Box<Node>, Vec<Node>, Box<Node>),
+    // Calling a function inside an expression, name and arguments
+    FnCall(Box<Node>, Vec<Node>),
+    Return(Option<Box<Node>>),
     If(Box<Node>, Box<Node>),
     IfElse(Box<Node>, Box<Node>, Box<Node>),
+    While(Box<Node>, Box<Node>),
+    // Start, end and whether the end is included
+    Range(Box<Node>, Box<Node>, bool),
+    // Loop variable, range, optional step and body
+    For(Box<Node>, Box<Node>, Option<Box<Node>>, Box<Node>),
+    Break,
+    Continue,
+    // Code that could not be parsed, only in programs with syntax errors
+    Error,
+    // Code nested deeper than `MAX_NESTING`, with the span of the node that went too deep
+    TooDeep(Span),
+}
+
+impl NodeKind {
+    /// The nodes directly inside this one
+    pub fn children(&self) -> Vec<&Node> {
+        match self {
+            NodeKind::Num(_)
+            | NodeKind::Str(_)
+            | NodeKind::Bool(_)
+            | NodeKind::Identifier(_)
+            | NodeKind::Comment(_)
+            | NodeKind::Break
+            | NodeKind::Continue
+            | NodeKind::Error
+            | NodeKind::TooDeep(_) => vec![],
+
+            NodeKind::Add(left, right)
+            | NodeKind::Sub(left, right)
+            | NodeKind::Mul(left, right)
+            | NodeKind::Div(left, right)
+            | NodeKind::Mod(left, right)
+            | NodeKind::Pow(left, right)
+            | NodeKind::Eq(left, right)
+            | NodeKind::NotEq(left, right)
+            | NodeKind::Gt(left, right)
+            | NodeKind::Lt(left, right)
+            | NodeKind::GtEq(left, right)
+            | NodeKind::LtEq(left, right)
+            | NodeKind::And(left, right)
+            | NodeKind::Or(left, right)
+            | NodeKind::Assign(left, right)
+            | NodeKind::Let(left, right)
+            | NodeKind::If(left, right)
+            | NodeKind::While(left, right)
+            | NodeKind::Range(left, right, _) => vec![left, right],
+
+            NodeKind::Not(value)
+            | NodeKind::Neg(value)
+            | NodeKind::Pos(value)
+            | NodeKind::Print(value) => vec![value],
+            NodeKind::Return(value) => value.iter().map(|value| &**value).collect(),
+
+            NodeKind::Block(nodes) => nodes.iter().collect(),
+            NodeKind::Call(name, args) | NodeKind::FnCall(name, args) => {
+                std::iter::once(&**name).chain(args).collect()
+            }
+            NodeKind::Proc(name, params, body) | NodeKind::Fn(name, params, body) => {
+                std::iter::once(&**name)
+                    .chain(params)
+                    .chain(std::iter::once(&**body))
+                    .collect()
+            }
+            NodeKind::IfElse(condition, body, else_body) => vec![condition, body, else_body],
+            NodeKind::For(variable, range, step, body) => {
+                let mut children = vec![&**variable, &**range];
+                children.extend(step.as_deref());
+                children.push(body);
+                children
+            }
+        }
+    }
+}
+
+/// Turns a string literal from the source, quotes included, into the string it stands for
+pub fn unescape_string(literal: &str) -> String {
+    let mut string = String::new();
+    let mut chars = literal[1..literal.len() - 1].chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            string.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('n') => string.push('\n'),
+            Some('t') => string.push('\t'),
+            Some('"') => string.push('"'),
+            Some('\\') => string.push('\\'),
+            // Unknown escapes are kept as they are
+            Some(other) => {
+                string.push('\\');
+                string.push(other);
+            }
+            None => string.push('\\'),
+        }
+    }
+
+    string
 }
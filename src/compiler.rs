@@ -1,603 +1,1141 @@
-use crate::ast;
-use crate::ast::{Node, Type};
+use crate::ast::{Node, NodeKind, Span, Type};
+use crate::cancel::CancelToken;
+use crate::dump::{self, show_value, DumpFormat, ProcedureState};
+use crate::error::{CallFrame, ErrorKind, Limit, Location, RuntimeError};
+use crate::value::Value;
+use crate::Program;
+use std::cmp::Ordering;
 use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Write};
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+/// What the interpreter should do after running a statement
+#[derive(Clone, Debug, PartialEq)]
+enum Flow {
+    Normal,
+    Break,
+    Continue,
+    Return(Option<Value>),
+}
+
+#[derive(Clone, Debug)]
+struct Procedure {
+    params: Vec<String>,
+    body: Node,
+    // The source of the program that defined it, which is not always the one being run
+    source: Rc<str>,
+    // The whole definition, from `proc` or `fn` to the closing `}`
+    span: Span,
+    // Procedures defined with `fn` return a value and can be used inside expressions
+    returns_value: bool,
+}
+
+type NativeFn = dyn Fn(&[Value]) -> Result<Value, RuntimeError>;
+
+/// A function written in Rust that scripts can call like one defined with `fn`
+struct NativeFunction {
+    params: Vec<Type>,
+    function: Box<NativeFn>,
+}
+
+#[derive(Clone, Debug)]
+struct StackFrame {
+    name: String,
+    // Where the procedure was called from
+    call_span: Span,
+    call_source: Rc<str>,
+    // Index of the first scope that belongs to this call
+    scope_start: usize,
+}
+
+/// How many procedure calls can be active at once before the program is stopped
+pub const DEFAULT_MAX_CALL_DEPTH: usize = 1000;
+
+/// How much stack the interpreter uses by default, half of the 2 MB Rust gives a new thread
+pub const DEFAULT_MAX_STACK_BYTES: usize = 1024 * 1024;
+
+/// Settings that change how a program is run
+#[derive(Clone, Debug)]
+pub struct Options {
+    pub max_call_depth: usize,
+    // How many bytes of the thread's stack `run` can use, nested calls and expressions
+    // stop with an error instead of overflowing it. Only raise it on a thread with a bigger stack.
+    pub max_stack_bytes: usize,
+    // Only accept `true` and `false` as conditions, numbers become an error
+    pub strict_conditions: bool,
+    // How many statements and loop iterations a single run can go through
+    pub max_steps: Option<u64>,
+    // How many bytes of strings can be held at once, in variables and while computing them
+    pub max_memory: Option<usize>,
+    // How long a single run can take
+    pub timeout: Option<Duration>,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            max_call_depth: DEFAULT_MAX_CALL_DEPTH,
+            max_stack_bytes: DEFAULT_MAX_STACK_BYTES,
+            strict_conditions: false,
+            max_steps: None,
+            max_memory: None,
+            timeout: None,
+        }
+    }
+}
+
+/// Reading the clock takes longer than most steps, so the timeout is only checked this often
+const STEPS_BETWEEN_TIME_CHECKS: u64 = 256;
+
+/// Runs programs, keeping the global variables and procedures from one run to the next
+pub struct Interpreter<'io> {
+    // Source of the code being run, used to turn spans into lines and columns for errors
+    source: Rc<str>,
+    // Shared so calling a procedure does not copy its body
+    defined_procedures: HashMap<String, Rc<Procedure>>,
+    native_functions: HashMap<String, Rc<NativeFunction>>,
+    // Innermost scope is last, the first one holds the global variables
+    scopes: Vec<HashMap<String, Value>>,
+    // Procedures that are currently running, the innermost call is last
+    call_stack: Vec<StackFrame>,
+    options: Options,
+    // Steps taken in the current run
+    steps: u64,
+    // Bytes of the strings held by variables
+    memory_used: usize,
+    // When the current run has to stop
+    deadline: Option<Instant>,
+    // Address of the stack where the current run started
+    stack_start: usize,
+    cancel_token: CancelToken,
+    // Where `print` writes to
+    output: Box<dyn Write + 'io>,
+    // Not read by any statement yet
+    #[allow(dead_code)]
+    input: Box<dyn BufRead + 'io>,
+}
+
+impl Default for Interpreter<'static> {
+    fn default() -> Self {
+        Interpreter::new()
+    }
+}
 
-pub struct Interpreter {
-    code: Vec<Node>,
-    source_code: Vec<String>,
-    line_number: usize,
-    defined_procedures: HashMap<String, Node>,
-    defined_variables: HashMap<String, (Type, String)>,
+impl Interpreter<'static> {
+    /// An interpreter that prints to the standard output
+    pub fn new() -> Interpreter<'static> {
+        Interpreter::with_options(Options::default())
+    }
+
+    pub fn with_options(options: Options) -> Interpreter<'static> {
+        Interpreter::with_io(options, std::io::stdout(), BufReader::new(std::io::stdin()))
+    }
 }
 
-impl Interpreter {
-    pub fn interpret(source_code: Vec<String>, code: Vec<Node>) {
-        let mut interpreter = Interpreter {
-            code: code.clone(),
-            source_code,
-            line_number: 0,
-            defined_variables: HashMap::new(),
+impl<'io> Interpreter<'io> {
+    /// An interpreter that prints to `output` and reads from `input`
+    pub fn with_io(
+        options: Options,
+        output: impl Write + 'io,
+        input: impl BufRead + 'io,
+    ) -> Interpreter<'io> {
+        Interpreter {
+            output: Box::new(output),
+            input: Box::new(input),
+            source: Rc::from(""),
+            steps: 0,
+            memory_used: 0,
+            deadline: None,
+            stack_start: 0,
+            cancel_token: CancelToken::default(),
+            scopes: vec![HashMap::new()],
+            call_stack: vec![],
+            options,
             defined_procedures: HashMap::new(),
-        };
-        for (line_number, item) in code.iter().enumerate() {
-            interpreter.line_number = line_number;
-            interpreter.interpret_node(item.clone());
+            native_functions: HashMap::new(),
         }
-        println!(
-            "\n\nDEBUGGING:\nVARIABLES: {:?}\nPROCEDURES: {:?}",
-            interpreter.defined_variables, interpreter.defined_procedures
-        );
     }
 
-    fn error(msg: String) {
-        println!("{}", msg);
-        std::process::exit(1);
+    /// A handle that stops the running program from another thread
+    pub fn cancel_token(&self) -> CancelToken {
+        self.cancel_token.clone()
     }
 
-    fn get_variable(&mut self, var: String) -> (Type, String) {
-        let mut output = self.defined_variables.get(&var).unwrap().clone();
+    /// Makes a Rust function callable from scripts, in expressions and with `call`.
+    /// Arguments are checked against `params` before it runs, `Type::Any` accepts every
+    /// value. Errors it gives back point at the call. A procedure or function defined by
+    /// a script with the same name is used instead.
+    pub fn register_fn<F>(&mut self, name: &str, params: &[Type], function: F)
+    where
+        F: Fn(&[Value]) -> Result<Value, RuntimeError> + 'static,
+    {
+        self.native_functions.insert(
+            name.to_string(),
+            Rc::new(NativeFunction {
+                params: params.to_vec(),
+                function: Box::new(function),
+            }),
+        );
+    }
 
-        let identifier_regex = regex::Regex::new(r"[a-zA-Z_][a-zA-Z_0-9]*").unwrap();
+    /// Runs every statement of the program. Variables and procedures it defines at the
+    /// top level are still there for the next program.
+    pub fn run(&mut self, program: &Program) -> Result<(), RuntimeError> {
+        self.source = Rc::from(program.source.as_str());
+        self.stack_start = stack_address();
+        self.steps = 0;
+        // A timeout too far in the future to be a time on the clock never runs out
+        self.deadline = self
+            .options
+            .timeout
+            .and_then(|timeout| Instant::now().checked_add(timeout));
+
+        // `break`, `continue`, `return` and parameter lists are checked before anything runs
+        for item in &program.statements {
+            self.check_control_flow(item, false, None)?;
+        }
 
-        if output.0 == Type::Identifier {
-            output = self.get_variable(output.1.clone());
+        for item in &program.statements {
+            self.interpret_node(item)?;
         }
 
-        output
-    }
-
-    fn get_string(string: String) -> String {
-        // Remove the first \" and last \" and then return it using regex
-        let string_regex = regex::Regex::new(r#"(")(.*)(")"#).unwrap();
-        string_regex.replace_all(&string, "$2").to_string()
-    }
-
-    fn get_type_and_value(&mut self, node: Node) -> (ast::Type, String) {
-        match node {
-            Node::Num(num) => return (ast::Type::Num, num),
-            Node::Str(le_str) => {
-                return (
-                    ast::Type::Str,
-                    Interpreter::get_string(le_str).replace("\\n", "\n"),
-                );
-            } // `le` doesn't signify anything here
-            Node::Identifier(ident) => return (ast::Type::Identifier, ident),
-            Node::Add(left_node, right_node) => {
-                // Check if left and right are numbers
-                let (mut left_type, mut left) = self.get_type_and_value(*left_node);
-                let (mut right_type, mut right) = self.get_type_and_value(*right_node);
-
-                // Checking left
-                if left_type == ast::Type::Identifier {
-                    let (this_left_type, left_value) = self.get_variable(left);
-                    left_type = this_left_type;
-                    left = left_value;
-                }
+        let end = Span {
+            start: program.source.len(),
+            end: program.source.len(),
+        };
+        self.output
+            .flush()
+            .map_err(|error| self.io_error(error, end))
+    }
 
-                if left_type == ast::Type::Str {
-                    // left = Interpreter::get_string(left);
-                } else if left_type != ast::Type::Num {
-                    Interpreter::error(format!(
-                        "Code:\n{} | {}\nProblem: `{}` is not a number.",
-                        self.line_number, self.source_code[self.line_number], left
-                    ));
-                }
+    /// Describes the global variables and the procedures defined by scripts, sorted by name
+    pub fn dump_state(&self, format: DumpFormat) -> String {
+        let mut variables: Vec<(&str, &Value)> = self.scopes[0]
+            .iter()
+            .map(|(name, value)| (name.as_str(), value))
+            .collect();
+        variables.sort_by_key(|(name, _)| *name);
+
+        let mut procedures: Vec<ProcedureState> = self
+            .defined_procedures
+            .iter()
+            .map(|(name, procedure)| ProcedureState {
+                name,
+                params: &procedure.params,
+                returns_value: procedure.returns_value,
+                location: Location::new(&procedure.source, procedure.span),
+            })
+            .collect();
+        procedures.sort_by_key(|procedure| procedure.name);
+
+        dump::render(&variables, &procedures, format)
+    }
 
-                // Checking right
-                if right_type == ast::Type::Identifier {
-                    let (this_right_type, right_value) = self.get_variable(right);
-                    right_type = this_right_type;
-                    right = right_value;
+    /// `returns_value` is `None` outside of procedures, otherwise it tells whether
+    /// the surrounding procedure was defined with `fn`
+    fn check_control_flow(
+        &self,
+        node: &Node,
+        in_loop: bool,
+        returns_value: Option<bool>,
+    ) -> Result<(), RuntimeError> {
+        self.check_stack(node.span)?;
+
+        match &node.kind {
+            NodeKind::Break | NodeKind::Continue if !in_loop => {
+                let keyword = if let NodeKind::Break = node.kind {
+                    "break"
+                } else {
+                    "continue"
+                };
+                return Err(self
+                    .error(
+                        ErrorKind::ControlFlow,
+                        format!("`{}` can only be used inside a loop.", keyword),
+                        node.span,
+                    )
+                    .with_label("not inside a loop".to_string()));
+            }
+            NodeKind::Return(value) => match (returns_value, value) {
+                (None, _) => {
+                    return Err(self
+                        .error(
+                            ErrorKind::ControlFlow,
+                            "`return` can only be used inside a procedure or function.".to_string(),
+                            node.span,
+                        )
+                        .with_label("not inside a procedure or function".to_string()))
                 }
-
-                if right_type == ast::Type::Str {
-                    // right = Interpreter::get_string(right);
-                } else if right_type != ast::Type::Num {
-                    Interpreter::error(format!(
-                        "Code:\n{} | {}\nProblem: `{}` is not a number.",
-                        self.line_number, self.source_code[self.line_number], right
-                    ));
+                (Some(false), Some(_)) => {
+                    return Err(self
+                        .error(
+                            ErrorKind::ControlFlow,
+                            "Procedures cannot return a value.".to_string(),
+                            node.span,
+                        )
+                        .with_label("returns a value".to_string())
+                        .with_help("define it with `fn` instead of `proc`".to_string()))
                 }
-
-                // Checking if both are same type
-                if left_type != right_type {
-                    Interpreter::error(format!(
-                        "Code:\n{} | {}\nProblem: `{}` and `{}` are not the same type.",
-                        self.line_number, self.source_code[self.line_number], left, right
-                    ));
+                (Some(true), None) => {
+                    return Err(self
+                        .error(
+                            ErrorKind::ControlFlow,
+                            "`return` inside a function needs a value.".to_string(),
+                            node.span,
+                        )
+                        .with_label("missing a value".to_string()))
                 }
-                // Check if both are numbers
-                if left_type == ast::Type::Num && right_type == ast::Type::Num {
-                    return (
-                        ast::Type::Num,
-                        format!(
-                            "{}",
-                            left.parse::<f64>().unwrap() + right.parse::<f64>().unwrap()
-                        ),
-                    );
-                } else if left_type == ast::Type::Str && right_type == ast::Type::Str {
-                    // Check if both are strings
-                    return (ast::Type::Str, format!("{}{}", left, right));
+                _ => {}
+            },
+            NodeKind::Block(nodes) => {
+                for node in nodes {
+                    self.check_control_flow(node, in_loop, returns_value)?;
                 }
+            }
+            NodeKind::If(_, body) => self.check_control_flow(body, in_loop, returns_value)?,
+            NodeKind::IfElse(_, body, else_body) => {
+                self.check_control_flow(body, in_loop, returns_value)?;
+                self.check_control_flow(else_body, in_loop, returns_value)?;
+            }
+            NodeKind::While(_, body) | NodeKind::For(_, _, _, body) => {
+                self.check_control_flow(body, true, returns_value)?
+            }
+            // A procedure can be called from anywhere, so it never counts as being inside a loop
+            NodeKind::Proc(_, params, body) => {
+                self.check_params(params)?;
+                self.check_control_flow(body, false, Some(false))?
+            }
+            NodeKind::Fn(_, params, body) => {
+                self.check_params(params)?;
+                self.check_control_flow(body, false, Some(true))?
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
 
-                return (ast::Type::Invalid, "".to_string());
+    fn check_params(&self, params: &[Node]) -> Result<(), RuntimeError> {
+        for (index, param_node) in params.iter().enumerate() {
+            let param = Interpreter::get_name(param_node);
+
+            if params[..index]
+                .iter()
+                .any(|earlier| Interpreter::get_name(earlier) == param)
+            {
+                return Err(self
+                    .error(
+                        ErrorKind::InvalidDefinition,
+                        format!("Parameter `{}` is declared twice.", param),
+                        param_node.span,
+                    )
+                    .with_label("declared again here".to_string()));
             }
+        }
 
-            Node::Sub(left_node, right_node) => {
-                // Check if left and right are numbers
-                let (mut left_type, mut left) = self.get_type_and_value(*left_node);
-                let (mut right_type, mut right) = self.get_type_and_value(*right_node);
+        Ok(())
+    }
 
-                // Checking left
-                if left_type == ast::Type::Identifier {
-                    let (this_left_type, left_value) = self.get_variable(left);
-                    left_type = this_left_type;
-                    left = left_value;
-                }
-                if left_type != ast::Type::Num {
-                    Interpreter::error(format!(
-                        "Code:\n{} | {}\nProblem: `{}` is not a number.",
-                        self.line_number, self.source_code[self.line_number], left
-                    ));
-                }
+    /// Builds an error pointing at `span`, remembering which procedures were running
+    fn error(&self, kind: ErrorKind, message: String, span: Span) -> RuntimeError {
+        self.locate(RuntimeError::new(kind, message), span)
+    }
 
-                // Checking right
-                if right_type == ast::Type::Identifier {
-                    let (this_right_type, right_value) = self.get_variable(right);
-                    right_type = this_right_type;
-                    right = right_value;
-                }
+    fn locate(&self, mut error: RuntimeError, span: Span) -> RuntimeError {
+        error.location = Box::new(Location::new(&self.source, span));
+        error.call_stack = self
+            .call_stack
+            .iter()
+            .rev()
+            .map(|frame| CallFrame {
+                name: frame.name.clone(),
+                location: Location::new(&frame.call_source, frame.call_span),
+            })
+            .collect();
+
+        error
+    }
 
-                if right_type != ast::Type::Num {
-                    Interpreter::error(format!(
-                        "Code:\n{} | {}\nProblem: `{}` is not a number.",
-                        self.line_number, self.source_code[self.line_number], right
-                    ));
-                }
+    fn io_error(&self, error: std::io::Error, span: Span) -> RuntimeError {
+        self.error(
+            ErrorKind::Io,
+            format!("The output could not be written: {}.", error),
+            span,
+        )
+    }
 
-                // Check if both are numbers
-                if left_type == ast::Type::Num && right_type == ast::Type::Num {
-                    return (
-                        ast::Type::Num,
-                        format!(
-                            "{}",
-                            left.parse::<f64>().unwrap() - right.parse::<f64>().unwrap()
-                        ),
-                    );
-                }
+    /// Counts one statement or loop iteration, stopping the program once it ran too long
+    /// or was cancelled
+    fn step(&mut self, span: Span) -> Result<(), RuntimeError> {
+        self.steps += 1;
+
+        if self.cancel_token.take() {
+            return Err(self
+                .error(
+                    ErrorKind::Cancelled,
+                    "The program was stopped.".to_string(),
+                    span,
+                )
+                .with_label("stopped here".to_string()));
+        }
 
-                return (ast::Type::Invalid, "".to_string());
+        if let Some(max_steps) = self.options.max_steps {
+            if self.steps > max_steps {
+                return Err(self
+                    .error(
+                        ErrorKind::LimitExceeded(Limit::Steps),
+                        format!("The program took more than {} steps.", max_steps),
+                        span,
+                    )
+                    .with_help(
+                        "every statement and every iteration of a loop is a step".to_string(),
+                    ));
             }
+        }
 
-            Node::Mul(left_node, right_node) => {
-                // Check if left and right are numbers
-                let (mut left_type, mut left) = self.get_type_and_value(*left_node);
-                let (mut right_type, mut right) = self.get_type_and_value(*right_node);
+        if let Some(deadline) = self.deadline {
+            if self.steps.is_multiple_of(STEPS_BETWEEN_TIME_CHECKS) && Instant::now() > deadline {
+                return Err(self.error(
+                    ErrorKind::LimitExceeded(Limit::Time),
+                    format!(
+                        "The program ran for longer than {:?}.",
+                        self.options.timeout.unwrap()
+                    ),
+                    span,
+                ));
+            }
+        }
 
-                // Checking left
-                if left_type == ast::Type::Identifier {
-                    let (this_left_type, left_value) = self.get_variable(left);
-                    left_type = this_left_type;
-                    left = left_value;
-                }
+        Ok(())
+    }
 
-                if left_type != ast::Type::Num {
-                    Interpreter::error(format!(
-                        "Code:\n{} | {}\nProblem: `{}` is not a number.",
-                        self.line_number, self.source_code[self.line_number], left
-                    ));
-                }
+    /// Stops the program before deeply nested calls or expressions overflow the stack
+    fn check_stack(&self, span: Span) -> Result<(), RuntimeError> {
+        if stack_address().abs_diff(self.stack_start) > self.options.max_stack_bytes {
+            return Err(self
+                .error(
+                    ErrorKind::RecursionLimit,
+                    format!(
+                        "The program ran out of stack space while {} procedures were running.",
+                        self.call_stack.len()
+                    ),
+                    span,
+                )
+                .with_help(
+                    "calls, blocks and expressions nested inside each other all use the stack"
+                        .to_string(),
+                ));
+        }
 
-                // Checking right
-                if right_type == ast::Type::Identifier {
-                    let (this_right_type, right_value) = self.get_variable(right);
-                    right_type = this_right_type;
-                    right = right_value;
-                }
+        Ok(())
+    }
 
-                if right_type != ast::Type::Num {
-                    Interpreter::error(format!(
-                        "Code:\n{} | {}\nProblem: `{}` is not a number.",
-                        self.line_number, self.source_code[self.line_number], right
-                    ));
-                }
+    /// Makes sure `bytes` more of strings can be held
+    fn check_memory(&self, bytes: usize, span: Span) -> Result<(), RuntimeError> {
+        match self.options.max_memory {
+            Some(max_memory) if self.memory_used + bytes > max_memory => Err(self
+                .error(
+                    ErrorKind::LimitExceeded(Limit::Memory),
+                    format!(
+                        "The program needed more than {} bytes of memory for strings.",
+                        max_memory
+                    ),
+                    span,
+                )
+                .with_label(format!("this needs {} more bytes", bytes))),
+            _ => Ok(()),
+        }
+    }
 
-                // Check if both are numbers
-                if left_type == ast::Type::Num && right_type == ast::Type::Num {
-                    return (
-                        ast::Type::Num,
-                        format!(
-                            "{}",
-                            left.parse::<f64>().unwrap() * right.parse::<f64>().unwrap()
-                        ),
-                    );
-                }
+    fn arity_error(&self, name: &str, params: usize, args: usize, span: Span) -> RuntimeError {
+        self.error(
+            ErrorKind::Arity,
+            format!(
+                "`{}` expects {} parameter(s) but was called with {} argument(s).",
+                name, params, args
+            ),
+            span,
+        )
+        .with_label(format!("called with {} argument(s)", args))
+    }
+
+    /// Finds the innermost visible scope that has the variable. A procedure only
+    /// sees its own scopes and the global one, never the scopes of its caller.
+    fn find_scope(&self, var: &str) -> Option<usize> {
+        let frame_start = match self.call_stack.last() {
+            Some(frame) => frame.scope_start,
+            None => 0,
+        };
 
-                return (ast::Type::Invalid, "".to_string());
+        for index in (frame_start..self.scopes.len()).rev() {
+            if self.scopes[index].contains_key(var) {
+                return Some(index);
             }
+        }
 
-            Node::Div(left_node, right_node) => {
-                // Check if left and right are numbers
-                let (mut left_type, mut left) = self.get_type_and_value(*left_node);
-                let (mut right_type, mut right) = self.get_type_and_value(*right_node);
+        if self.scopes[0].contains_key(var) {
+            return Some(0);
+        }
 
-                // Checking left
-                if left_type == ast::Type::Identifier {
-                    let (this_left_type, left_value) = self.get_variable(left);
-                    left_type = this_left_type;
-                    left = left_value;
-                }
+        None
+    }
 
-                if left_type != ast::Type::Num {
-                    Interpreter::error(format!(
-                        "Code:\n{} | {}\nProblem: `{}` is not a number.",
-                        self.line_number, self.source_code[self.line_number], left
-                    ));
-                }
+    fn push_scope(&mut self) {
+        self.scopes.push(HashMap::new());
+    }
 
-                // Checking right
-                if right_type == ast::Type::Identifier {
-                    let (this_right_type, right_value) = self.get_variable(right);
-                    right_type = this_right_type;
-                    right = right_value;
-                }
+    fn pop_scope(&mut self) {
+        let scope = self.scopes.pop().unwrap();
+        for value in scope.values() {
+            self.memory_used -= string_bytes(value);
+        }
+    }
 
-                if right_type != ast::Type::Num {
-                    Interpreter::error(format!(
-                        "Code:\n{} | {}\nProblem: `{}` is not a number.",
-                        self.line_number, self.source_code[self.line_number], right
-                    ));
-                }
+    /// `let` always creates the variable in the innermost scope, shadowing any outer one
+    fn declare_variable(
+        &mut self,
+        var: String,
+        value: Value,
+        span: Span,
+    ) -> Result<(), RuntimeError> {
+        let index = self.scopes.len() - 1;
+        self.store_variable(index, var, value, span)
+    }
 
-                // Check if both are numbers
-                if left_type == ast::Type::Num && right_type == ast::Type::Num {
-                    return (
-                        ast::Type::Num,
-                        format!(
-                            "{}",
-                            left.parse::<f64>().unwrap() / right.parse::<f64>().unwrap()
-                        ),
-                    );
-                }
+    /// Plain assignment updates the closest visible variable, or creates it in the innermost scope
+    fn assign_variable(
+        &mut self,
+        var: String,
+        value: Value,
+        span: Span,
+    ) -> Result<(), RuntimeError> {
+        match self.find_scope(&var) {
+            Some(index) => self.store_variable(index, var, value, span),
+            None => self.declare_variable(var, value, span),
+        }
+    }
 
-                return (ast::Type::Invalid, "".to_string());
-            }
+    /// Every variable is set through here, so the memory they use is always known.
+    /// Copies of a string count again, since every variable holds its own.
+    fn store_variable(
+        &mut self,
+        index: usize,
+        var: String,
+        value: Value,
+        span: Span,
+    ) -> Result<(), RuntimeError> {
+        let old_bytes = self.scopes[index].get(&var).map_or(0, string_bytes);
+        let new_bytes = string_bytes(&value);
+        if new_bytes > old_bytes {
+            self.check_memory(new_bytes - old_bytes, span)?;
+        }
 
-            Node::Eq(left, right) => {
-                let (mut left_type, mut left) = self.get_type_and_value(*left);
-                let (mut right_type, mut right) = self.get_type_and_value(*right);
+        self.memory_used = self.memory_used + new_bytes - old_bytes;
+        self.scopes[index].insert(var, value);
 
-                // Checking left
-                if left_type == ast::Type::Identifier {
-                    let (this_left_type, left_value) = self.get_variable(left);
-                    left_type = this_left_type;
-                    left = left_value;
-                }
+        Ok(())
+    }
 
-                // Checking right
-                if right_type == ast::Type::Identifier {
-                    let (this_right_type, right_value) = self.get_variable(right);
-                    right_type = this_right_type;
-                    right = right_value;
-                }
+    fn get_variable(&self, var: &str, span: Span) -> Result<Value, RuntimeError> {
+        match self.find_scope(var) {
+            Some(index) => Ok(self.scopes[index][var].clone()),
+            None => Err(self
+                .error(
+                    ErrorKind::Undefined,
+                    format!("`{}` is not defined.", var),
+                    span,
+                )
+                .with_label("not defined".to_string())),
+        }
+    }
 
-                if left == right {
-                    return (ast::Type::Num, "1".to_string());
-                } else {
-                    return (ast::Type::Num, "0".to_string());
-                }
+    /// Names (of variables, procedures and parameters) are identifier nodes in the AST
+    fn get_name(node: &Node) -> String {
+        match &node.kind {
+            NodeKind::Identifier(name) => name.clone(),
+            _ => unreachable!("the parser only puts identifiers where names are expected"),
+        }
+    }
 
-                return (ast::Type::Invalid, "".to_string());
-            }
+    /// Orders two values of the same type. Numbers are compared by value and strings
+    /// alphabetically, `None` means the values cannot be ordered (e.g. `0 / 0`)
+    fn compare(&mut self, left: &Node, right: &Node) -> Result<Option<Ordering>, RuntimeError> {
+        let span = Span {
+            start: left.span.start,
+            end: right.span.end,
+        };
+        let left = self.get_value(left)?;
+        let right = self.get_value(right)?;
+
+        match (&left, &right) {
+            (Value::Num(left), Value::Num(right)) => Ok(left.partial_cmp(right)),
+            (Value::Str(left), Value::Str(right)) => Ok(Some(left.cmp(right))),
+            (Value::Bool(left), Value::Bool(right)) => Ok(Some(left.cmp(right))),
+            _ => Err(self
+                .error(
+                    ErrorKind::Type,
+                    format!(
+                        "`{}` and `{}` are not the same type, so they cannot be compared.",
+                        show_value(&left),
+                        show_value(&right)
+                    ),
+                    span,
+                )
+                .with_label(format!("{} and {}", left.get_type(), right.get_type()))),
+        }
+    }
 
-            Node::NotEq(left, right) => {
-                let (mut left_type, mut left) = self.get_type_and_value(*left);
-                let (mut right_type, mut right) = self.get_type_and_value(*right);
+    /// Decides whether a condition holds. Booleans are always accepted, numbers
+    /// count as true when they are not 0 unless strict conditions are turned on.
+    fn is_true(&mut self, condition: &Node) -> Result<bool, RuntimeError> {
+        match self.get_value(condition)? {
+            Value::Bool(value) => Ok(value),
+            Value::Num(num) if !self.options.strict_conditions => Ok(num != 0.0),
+            Value::Num(num) => Err(self
+                .error(
+                    ErrorKind::Type,
+                    format!(
+                    "`{}` is a number, but conditions have to be `true` or `false` in strict mode.",
+                    num
+                ),
+                    condition.span,
+                )
+                .with_label("this is a number".to_string())
+                .with_help("compare it instead, like `x != 0`".to_string())),
+            value => Err(self
+                .error(
+                    ErrorKind::Type,
+                    format!("`{}` is not a valid condition.", show_value(&value)),
+                    condition.span,
+                )
+                .with_label(format!("this is a {}", value.get_type()))),
+        }
+    }
 
-                // Checking left
-                if left_type == ast::Type::Identifier {
-                    let (this_left_type, left_value) = self.get_variable(left);
-                    left_type = this_left_type;
-                    left = left_value;
-                }
+    fn get_number(&mut self, node: &Node) -> Result<f64, RuntimeError> {
+        match self.get_value(node)? {
+            Value::Num(num) => Ok(num),
+            value => Err(self
+                .error(
+                    ErrorKind::Type,
+                    format!("`{}` is not a number.", show_value(&value)),
+                    node.span,
+                )
+                .with_label(format!("this is a {}", value.get_type()))),
+        }
+    }
 
-                // Checking right
-                if right_type == ast::Type::Identifier {
-                    let (this_right_type, right_value) = self.get_variable(right);
-                    right_type = this_right_type;
-                    right = right_value;
-                }
+    fn get_value(&mut self, node: &Node) -> Result<Value, RuntimeError> {
+        self.check_stack(node.span)?;
 
-                if left != right {
-                    return (ast::Type::Num, "1".to_string());
-                } else {
-                    return (ast::Type::Num, "0".to_string());
+        let value = match &node.kind {
+            NodeKind::Num(num) => Value::Num(*num),
+            NodeKind::Str(string) => Value::Str(string.clone()),
+            NodeKind::Bool(value) => Value::Bool(*value),
+            NodeKind::Identifier(ident) => self.get_variable(ident, node.span)?,
+
+            NodeKind::Add(left_node, right_node) => {
+                let left = self.get_value(left_node)?;
+                let right = self.get_value(right_node)?;
+
+                match (left, right) {
+                    (Value::Num(left), Value::Num(right)) => Value::Num(left + right),
+                    (Value::Str(left), Value::Str(right)) => {
+                        self.check_memory(left.len() + right.len(), node.span)?;
+                        Value::Str(left + &right)
+                    }
+                    (left, right) => {
+                        // Only numbers and strings can be added
+                        for (value, span) in &[(&left, left_node.span), (&right, right_node.span)] {
+                            if !matches!(value, Value::Num(_) | Value::Str(_)) {
+                                return Err(self
+                                    .error(
+                                        ErrorKind::Type,
+                                        format!("`{}` is not a number.", show_value(value)),
+                                        *span,
+                                    )
+                                    .with_label(format!("this is a {}", value.get_type())));
+                            }
+                        }
+
+                        return Err(self
+                            .error(
+                                ErrorKind::Type,
+                                format!(
+                                    "`{}` and `{}` are not the same type.",
+                                    show_value(&left),
+                                    show_value(&right)
+                                ),
+                                node.span,
+                            )
+                            .with_label(format!("{} and {}", left.get_type(), right.get_type())));
+                    }
                 }
+            }
 
-                return (ast::Type::Invalid, "".to_string());
+            NodeKind::Sub(left, right) => {
+                let left = self.get_number(left)?;
+                let right = self.get_number(right)?;
+                Value::Num(left - right)
             }
 
-            Node::Gt(left, right) => {
-                let (mut left_type, mut left) = self.get_type_and_value(*left);
-                let (mut right_type, mut right) = self.get_type_and_value(*right);
+            NodeKind::Mul(left, right) => {
+                let left = self.get_number(left)?;
+                let right = self.get_number(right)?;
+                Value::Num(left * right)
+            }
 
-                // Checking left
-                if left_type == ast::Type::Identifier {
-                    let (this_left_type, left_value) = self.get_variable(left);
-                    left_type = this_left_type;
-                    left = left_value;
-                }
+            NodeKind::Div(left, right) => {
+                let left = self.get_number(left)?;
+                let right = self.get_number(right)?;
+                Value::Num(left / right)
+            }
 
-                // Checking right
-                if right_type == ast::Type::Identifier {
-                    let (this_right_type, right_value) = self.get_variable(right);
-                    right_type = this_right_type;
-                    right = right_value;
-                }
+            // The result has the sign of the right side, so `-7 % 3` is 2 and `7 % -3` is -2
+            NodeKind::Mod(left, right) => {
+                let left = self.get_number(left)?;
+                let right = self.get_number(right)?;
+                Value::Num(left - right * (left / right).floor())
+            }
 
-                if left_type != ast::Type::Num {
-                    Interpreter::error(format!(
-                        "Code:\n{} | {}\nProblem: `{}` is not a number.",
-                        self.line_number, self.source_code[self.line_number], left
-                    ));
-                }
+            NodeKind::Pow(left, right) => {
+                let left = self.get_number(left)?;
+                let right = self.get_number(right)?;
+                Value::Num(left.powf(right))
+            }
 
-                if right_type != ast::Type::Num {
-                    Interpreter::error(format!(
-                        "Code:\n{} | {}\nProblem: `{}` is not a number.",
-                        self.line_number, self.source_code[self.line_number], right
-                    ));
-                }
+            // Values of different types are never equal
+            NodeKind::Eq(left, right) => {
+                let left = self.get_value(left)?;
+                let right = self.get_value(right)?;
+                Value::Bool(left == right)
+            }
 
-                if left.parse::<f64>().unwrap() > right.parse::<f64>().unwrap() {
-                    return (ast::Type::Num, "1".to_string());
-                } else {
-                    return (ast::Type::Num, "0".to_string());
-                }
+            NodeKind::NotEq(left, right) => {
+                let left = self.get_value(left)?;
+                let right = self.get_value(right)?;
+                Value::Bool(left != right)
+            }
 
-                return (ast::Type::Invalid, "".to_string());
+            NodeKind::Gt(left, right) => {
+                let ordering = self.compare(left, right)?;
+                Value::Bool(ordering == Some(Ordering::Greater))
             }
 
-            Node::Lt(left, right) => {
-                let (mut left_type, mut left) = self.get_type_and_value(*left);
-                let (mut right_type, mut right) = self.get_type_and_value(*right);
+            NodeKind::Lt(left, right) => {
+                let ordering = self.compare(left, right)?;
+                Value::Bool(ordering == Some(Ordering::Less))
+            }
 
-                // Checking left
-                if left_type == ast::Type::Identifier {
-                    let (this_left_type, left_value) = self.get_variable(left);
-                    left_type = this_left_type;
-                    left = left_value;
-                }
+            NodeKind::GtEq(left, right) => {
+                let ordering = self.compare(left, right)?;
+                Value::Bool(matches!(
+                    ordering,
+                    Some(Ordering::Greater) | Some(Ordering::Equal)
+                ))
+            }
 
-                // Checking right
-                if right_type == ast::Type::Identifier {
-                    let (this_right_type, right_value) = self.get_variable(right);
-                    right_type = this_right_type;
-                    right = right_value;
-                }
+            NodeKind::LtEq(left, right) => {
+                let ordering = self.compare(left, right)?;
+                Value::Bool(matches!(
+                    ordering,
+                    Some(Ordering::Less) | Some(Ordering::Equal)
+                ))
+            }
 
-                if left_type != ast::Type::Num {
-                    Interpreter::error(format!(
-                        "Code:\n{} | {}\nProblem: `{}` is not a number.",
-                        self.line_number, self.source_code[self.line_number], left
-                    ));
-                }
+            // The right side is only evaluated when the left side does not decide the result
+            NodeKind::And(left, right) => Value::Bool(self.is_true(left)? && self.is_true(right)?),
 
-                if right_type != ast::Type::Num {
-                    Interpreter::error(format!(
-                        "Code:\n{} | {}\nProblem: `{}` is not a number.",
-                        self.line_number, self.source_code[self.line_number], right
-                    ));
-                }
+            NodeKind::Or(left, right) => Value::Bool(self.is_true(left)? || self.is_true(right)?),
 
-                if left.parse::<f64>().unwrap() < right.parse::<f64>().unwrap() {
-                    return (ast::Type::Num, "1".to_string());
-                } else {
-                    return (ast::Type::Num, "0".to_string());
+            NodeKind::Not(value) => Value::Bool(!self.is_true(value)?),
+
+            NodeKind::Neg(value) => Value::Num(-self.get_number(value)?),
+
+            // Unary plus leaves the number as is, but still makes sure it is a number
+            NodeKind::Pos(value) => Value::Num(self.get_number(value)?),
+
+            NodeKind::FnCall(name_node, args) => {
+                let name = Interpreter::get_name(name_node);
+
+                let returns_value = match self.defined_procedures.get(&name) {
+                    Some(procedure) => procedure.returns_value,
+                    None if self.native_functions.contains_key(&name) => {
+                        return self.call_native(&name, args, node.span);
+                    }
+                    None => {
+                        return Err(self
+                            .error(
+                                ErrorKind::Undefined,
+                                format!("`{}` is not a defined function.", name),
+                                name_node.span,
+                            )
+                            .with_label("not defined".to_string()))
+                    }
+                };
+
+                if !returns_value {
+                    return Err(self.error(
+                        ErrorKind::MissingReturnValue,
+                        format!(
+                            "`{}` is a procedure and does not return a value, so it cannot be used in an expression.",
+                            name
+                        ),
+                        name_node.span,
+                    )
+                    .with_label("defined with `proc`".to_string())
+                    .with_help(format!(
+                        "run it on its own with `call {};`",
+                        &self.source[node.span.start..node.span.end]
+                    )));
                 }
 
-                return (ast::Type::Invalid, "".to_string());
+                self.run_procedure(&name, args, node.span)?.unwrap()
             }
 
-            Node::Block(nodes) => {
-                let mut result = (ast::Type::Block, "".to_string());
-                return result; // No value for block
-            }
+            _ => unreachable!("the parser only puts expressions where values are expected"),
+        };
 
-            _ => return (ast::Type::Invalid, String::new()),
-        }
+        Ok(value)
     }
 
-    fn run_procedure(&mut self, proc_name: Node) {
-        let (proc_type, proc_value) = self.get_type_and_value(proc_name);
-        if proc_type != ast::Type::Identifier {
-            Interpreter::error(format!(
-                "Code:\n{} | {}\nProblem: `{}` is not a procedure.",
-                self.line_number, self.source_code[self.line_number], proc_value
-            ));
-        }
+    fn define_procedure(
+        &mut self,
+        name: &Node,
+        params: &[Node],
+        body: &Node,
+        returns_value: bool,
+        span: Span,
+    ) {
+        let name = Interpreter::get_name(name);
+        // Parameters that are declared twice were found before the program ran
+        let param_names = params.iter().map(Interpreter::get_name).collect();
+
+        // Add the procedure to the defined procedures
+        self.defined_procedures.insert(
+            name,
+            Rc::new(Procedure {
+                params: param_names,
+                body: body.clone(),
+                source: Rc::clone(&self.source),
+                span,
+                returns_value,
+            }),
+        );
+    }
 
-        let proc_name = proc_value;
+    /// Runs a procedure or function and gives back the value it returned,
+    /// `call_span` is the call that errors about it point at
+    fn run_procedure(
+        &mut self,
+        proc_name: &str,
+        args: &[Node],
+        call_span: Span,
+    ) -> Result<Option<Value>, RuntimeError> {
+        let procedure = Rc::clone(&self.defined_procedures[proc_name]);
+
+        if procedure.params.len() != args.len() {
+            return Err(self.arity_error(proc_name, procedure.params.len(), args.len(), call_span));
+        }
 
-        // Check if procedure exists
-        if !self.defined_procedures.contains_key(&proc_name) {
-            Interpreter::error(format!(
-                "Code:\n{} | {}\nProblem: `{}` is not a procedure.",
-                self.line_number, self.source_code[self.line_number], proc_name
-            ));
+        // Arguments are evaluated before any parameter is bound, so `call f(b, a);` sees the caller's values
+        let mut values = vec![];
+        for arg in args {
+            values.push(self.get_value(arg)?);
         }
 
-        let procedure = self.defined_procedures.get(&proc_name).unwrap();
+        if self.call_stack.len() >= self.options.max_call_depth {
+            return Err(self.error(
+                ErrorKind::RecursionLimit,
+                format!(
+                    "Recursion limit exceeded, `{}` was called while {} procedures were already running.",
+                    proc_name, self.options.max_call_depth
+                ),
+                call_span,
+            )
+            .with_help("raise the limit with `--max-depth=N` if the recursion is expected".to_string()));
+        }
 
-        if let ast::Node::Block(nodes) = procedure.clone() {
-            for node in nodes {
-                self.interpret_node(node);
+        // Parameters live in a new scope that starts the procedure's frame
+        self.push_scope();
+        self.call_stack.push(StackFrame {
+            name: proc_name.to_string(),
+            call_span,
+            call_source: Rc::clone(&self.source),
+            scope_start: self.scopes.len() - 1,
+        });
+        let caller_source = std::mem::replace(&mut self.source, Rc::clone(&procedure.source));
+        let mut declared = Ok(());
+        for (param, value) in procedure.params.iter().zip(values) {
+            declared = self.declare_variable(param.clone(), value, procedure.span);
+            if declared.is_err() {
+                break;
             }
         }
-    }
 
-    fn interpret_node(&mut self, node: Node) -> String {
-        let mut compiled_output = String::new();
+        let result = match declared.and_then(|()| self.run_block(&procedure.body)) {
+            Ok(Flow::Return(value)) => Ok(value),
+            Ok(_) => Ok(None),
+            Err(error) => Err(error),
+        };
 
-        match node {
-            Node::Assign(identifier, value) => {
-                let (mut identifier_type, mut identifier_value) =
-                    self.get_type_and_value(*identifier);
-                let (mut value_type, mut value) = self.get_type_and_value(*value);
+        let result = match result {
+            Ok(None) if procedure.returns_value => Err(self
+                .error(
+                    ErrorKind::MissingReturnValue,
+                    format!(
+                        "Function `{}` finished without returning a value.",
+                        proc_name
+                    ),
+                    // Points at the closing `}` of the body
+                    Span {
+                        start: procedure.body.span.end - 1,
+                        end: procedure.body.span.end,
+                    },
+                )
+                .with_label("ends here without `return`".to_string())),
+            result => result,
+        };
 
-                // Checking identifier
-                if identifier_type != ast::Type::Identifier {
-                    Interpreter::error(format!(
-                        "Code:\n{} | {}\nProblem: `{}` is not a valid identifier.",
-                        self.source_code[self.line_number], self.line_number, identifier_value
-                    ));
-                }
+        // The frame is removed even when the procedure failed, so the interpreter stays usable
+        self.source = caller_source;
+        self.call_stack.pop();
+        self.pop_scope();
 
-                // Checking value
-                if !vec![ast::Type::Num, ast::Type::Str, ast::Type::Identifier]
-                    .contains(&value_type)
-                {
-                    Interpreter::error(format!(
-                        "Code:\n{} | {}\nProblem: `{}` is not a valid value.",
-                        self.source_code[self.line_number], self.line_number, value
-                    ));
-                }
-                if value_type == ast::Type::Identifier {
-                    let (this_value_type, value_value) = self.get_variable(value);
-                    value_type = this_value_type;
-                    value = value_value;
-                }
+        result
+    }
 
-                // Assign the value to the variable
-                self.defined_variables.insert(
-                    identifier_value.to_string(),
-                    (value_type, value.to_string()),
-                );
+    /// Calls a function registered from Rust after checking its arguments
+    fn call_native(
+        &mut self,
+        name: &str,
+        args: &[Node],
+        call_span: Span,
+    ) -> Result<Value, RuntimeError> {
+        let native = Rc::clone(&self.native_functions[name]);
+
+        if native.params.len() != args.len() {
+            return Err(self.arity_error(name, native.params.len(), args.len(), call_span));
+        }
+
+        let mut values = vec![];
+        for (index, (arg, param)) in args.iter().zip(&native.params).enumerate() {
+            let value = self.get_value(arg)?;
+            if *param != Type::Any && value.get_type() != *param {
+                return Err(self
+                    .error(
+                        ErrorKind::Type,
+                        format!(
+                            "`{}` expects a {} as argument {}, but `{}` is a {}.",
+                            name,
+                            param,
+                            index + 1,
+                            show_value(&value),
+                            value.get_type()
+                        ),
+                        arg.span,
+                    )
+                    .with_label(format!("this is a {}", value.get_type())));
             }
+            values.push(value);
+        }
 
-            Node::Print(value) => {
-                let (mut value_type, mut value) = self.get_type_and_value(*value);
+        let value = (native.function)(&values).map_err(|error| self.locate(error, call_span))?;
+        self.check_memory(string_bytes(&value), call_span)?;
 
-                // Checking value
-                if value_type == ast::Type::Invalid {
-                    Interpreter::error(format!(
-                        "Code:\n{} | {}\nProblem: `{}` is not of a valid type.",
-                        self.line_number, self.source_code[self.line_number], value
-                    ));
-                }
+        Ok(value)
+    }
 
-                if value_type == ast::Type::Identifier {
-                    let (this_value_type, value_value) = self.get_variable(value);
-                    value_type = this_value_type;
-                    value = value_value;
-                }
+    /// Runs the nodes of a block inside their own scope
+    fn run_block(&mut self, block: &Node) -> Result<Flow, RuntimeError> {
+        self.push_scope();
+        let flow = self.run_nodes(block);
+        self.pop_scope();
+
+        flow
+    }
 
-                if value_type == ast::Type::Str {
-                    // value = Interpreter::get_string(value);
+    fn run_nodes(&mut self, block: &Node) -> Result<Flow, RuntimeError> {
+        if let NodeKind::Block(nodes) = &block.kind {
+            for node in nodes {
+                let flow = self.interpret_node(node)?;
+                if flow != Flow::Normal {
+                    return Ok(flow);
                 }
+            }
+        }
+
+        Ok(Flow::Normal)
+    }
+
+    fn interpret_node(&mut self, node: &Node) -> Result<Flow, RuntimeError> {
+        self.step(node.span)?;
+        self.check_stack(node.span)?;
 
-                // Print the value
-                print!("{}", value);
+        match &node.kind {
+            NodeKind::Assign(identifier, value) => {
+                let value = self.get_value(value)?;
+                self.assign_variable(Interpreter::get_name(identifier), value, node.span)?;
             }
 
-            Node::Comment(comment) => {
-                // Do nothing
+            NodeKind::Let(identifier, value) => {
+                let value = self.get_value(value)?;
+                self.declare_variable(Interpreter::get_name(identifier), value, node.span)?;
             }
 
-            Node::Proc(name, nodes) => {
-                let (mut name_type, name) = self.get_type_and_value(*name);
+            NodeKind::Print(value) => {
+                let value = self.get_value(value)?;
 
-                if name_type != ast::Type::Identifier {
-                    Interpreter::error(format!(
-                        "Code:\n{} | {}\nProblem: `{}` is not a valid identifier.",
-                        self.line_number, self.source_code[self.line_number], name
-                    ));
-                }
+                write!(self.output, "{}", value)
+                    .map_err(|error| self.io_error(error, node.span))?;
+            }
 
-                let (mut procedure_type, mut procedure) = self.get_type_and_value(*nodes.clone());
+            NodeKind::Comment(_) => {
+                // Do nothing
+            }
 
-                if procedure_type != ast::Type::Block {
-                    Interpreter::error(format!(
-                        "Code:\n{} | {}\nProblem: `{}` is not a valid block.",
-                        self.line_number, self.source_code[self.line_number], procedure
-                    ));
-                }
+            NodeKind::Proc(name, params, body) => {
+                self.define_procedure(name, params, body, false, node.span);
+            }
 
-                // Add the procedure to the defined procedures
-                self.defined_procedures.insert(name.to_string(), *nodes);
+            NodeKind::Fn(name, params, body) => {
+                self.define_procedure(name, params, body, true, node.span);
             }
 
-            Node::Call(name) => {
-                let (mut name_type, name_value) = self.get_type_and_value(*name.clone());
+            NodeKind::Call(name_node, args) => {
+                let name = Interpreter::get_name(name_node);
 
-                if name_type != ast::Type::Identifier {
-                    Interpreter::error(format!(
-                        "Code:\n{} | {}\nProblem: `{}` is not a valid identifier.",
-                        self.line_number, self.source_code[self.line_number], name_value
-                    ));
+                if self.defined_procedures.contains_key(&name) {
+                    self.run_procedure(&name, args, node.span)?;
+                } else if self.native_functions.contains_key(&name) {
+                    // The value it gives back is not used
+                    self.call_native(&name, args, node.span)?;
+                } else {
+                    return Err(self
+                        .error(
+                            ErrorKind::Undefined,
+                            format!("`{}` is not a defined procedure.", name),
+                            name_node.span,
+                        )
+                        .with_label("not defined".to_string()));
                 }
+            }
 
-                // Check if the procedure exists
-                if !self.defined_procedures.contains_key(&name_value) {
-                    Interpreter::error(format!(
-                        "Code:\n{} | {}\nProblem: `{}` is not a defined procedure.",
-                        self.line_number, self.source_code[self.line_number], name_value
-                    ));
+            NodeKind::If(condition, body) => {
+                let condition = self.is_true(condition)?;
+                if condition {
+                    return self.run_block(body);
                 }
-
-                // Run the procedure
-                self.run_procedure(*name.clone());
             }
 
-            Node::If(condition, nodes) => {
-                let (mut condition_type, mut condition_value) = self.get_type_and_value(*condition);
+            NodeKind::IfElse(condition, body, else_body) => {
+                if self.is_true(condition)? {
+                    return self.run_block(body);
+                } else {
+                    return self.run_block(else_body);
+                }
+            }
 
-                if condition_type != ast::Type::Num {
-                    Interpreter::error(format!(
-                        "Code:\n{} | {}\nProblem: `{}` is not a valid condition.",
-                        self.line_number, self.source_code[self.line_number], condition_value
-                    ));
+            NodeKind::While(condition, body) => loop {
+                self.step(node.span)?;
+                if !self.is_true(condition)? {
+                    break;
                 }
 
-                if condition_value == "0" {
-                    // Do nothing
-                } else {
-                    if let ast::Node::Block(nodes) = *nodes.clone() {
-                        for node in nodes {
-                            compiled_output += &self.interpret_node(node);
-                        }
-                    }
+                match self.run_block(body)? {
+                    Flow::Break => break,
+                    Flow::Return(value) => return Ok(Flow::Return(value)),
+                    _ => {}
                 }
-            }
+            },
 
-            Node::IfElse(condition, nodes, else_nodes) => {
-                let (mut condition_type, mut condition_value) = self.get_type_and_value(*condition);
+            NodeKind::For(variable, range, step, body) => {
+                let variable = Interpreter::get_name(variable);
 
-                if condition_type != ast::Type::Num {
-                    Interpreter::error(format!(
-                        "Code:\n{} | {}\nProblem: `{}` is not a valid condition.",
-                        self.line_number, self.source_code[self.line_number], condition_value
-                    ));
+                let (start, end, inclusive) = match &range.kind {
+                    NodeKind::Range(start, end, inclusive) => {
+                        (self.get_number(start)?, self.get_number(end)?, *inclusive)
+                    }
+                    _ => unreachable!(),
+                };
+
+                let (step, step_span) = match step {
+                    Some(step) => (self.get_number(step)?, step.span),
+                    None => (1.0, node.span),
+                };
+
+                if step == 0.0 {
+                    return Err(self
+                        .error(
+                            ErrorKind::InvalidValue,
+                            "The step of a `for` loop cannot be 0.".to_string(),
+                            step_span,
+                        )
+                        .with_label("this is 0".to_string()));
                 }
 
-                if condition_value == "0" {
-                    if let ast::Node::Block(else_nodes) = *nodes.clone() {
-                        for node in else_nodes {
-                            compiled_output += &self.interpret_node(node);
-                        }
+                // Computing every value from the start avoids piling up floating point errors
+                let mut iteration = 0.0;
+                loop {
+                    self.step(node.span)?;
+                    let current = start + iteration * step;
+                    let in_range = match (step > 0.0, inclusive) {
+                        (true, false) => current < end,
+                        (true, true) => current <= end,
+                        (false, false) => current > end,
+                        (false, true) => current >= end,
+                    };
+
+                    if !in_range {
+                        break;
                     }
-                } else {
-                    if let ast::Node::Block(nodes) = *nodes.clone() {
-                        for node in nodes {
-                            compiled_output += &self.interpret_node(node);
-                        }
+
+                    // The loop variable only exists inside the loop
+                    self.push_scope();
+                    let flow = self
+                        .declare_variable(variable.clone(), Value::Num(current), node.span)
+                        .and_then(|()| self.run_block(body));
+                    self.pop_scope();
+
+                    match flow? {
+                        Flow::Break => break,
+                        Flow::Return(value) => return Ok(Flow::Return(value)),
+                        _ => {}
                     }
+
+                    iteration += 1.0;
                 }
             }
 
+            NodeKind::Block(_) => return self.run_block(node),
+
+            NodeKind::Break => return Ok(Flow::Break),
+
+            NodeKind::Continue => return Ok(Flow::Continue),
+
+            NodeKind::Return(value) => match value {
+                Some(value) => return Ok(Flow::Return(Some(self.get_value(value)?))),
+                None => return Ok(Flow::Return(None)),
+            },
+
             _ => {}
         }
 
-        compiled_output
+        Ok(Flow::Normal)
     }
 }
+
+/// The memory a value takes up, only strings are counted
+fn string_bytes(value: &Value) -> usize {
+    match value {
+        Value::Str(string) => string.len(),
+        _ => 0,
+    }
+}
+
+/// Roughly where the stack of the current thread is, compared to see how much of it is used
+fn stack_address() -> usize {
+    let marker = 0u8;
+    std::hint::black_box(&marker) as *const u8 as usize
+}
@@ -0,0 +1,173 @@
+use crate::ast::Span;
+use crate::error::Location;
+use std::fmt;
+
+const RED: &str = "\x1b[1;31m";
+const BLUE: &str = "\x1b[1;34m";
+const BOLD: &str = "\x1b[1m";
+const RESET: &str = "\x1b[0m";
+
+/// A problem to show to the user, pointing at the code that caused it
+#[derive(Clone, Debug, PartialEq)]
+pub struct Diagnostic {
+    pub message: String,
+    pub span: Span,
+    // Shown next to the underline
+    pub label: Option<String>,
+    pub notes: Vec<String>,
+    pub help: Option<String>,
+}
+
+impl Diagnostic {
+    pub fn new(message: String, span: Span) -> Diagnostic {
+        Diagnostic {
+            message,
+            span,
+            label: None,
+            notes: vec![],
+            help: None,
+        }
+    }
+
+    pub fn with_label(mut self, label: String) -> Diagnostic {
+        self.label = Some(label);
+        self
+    }
+
+    pub fn with_note(mut self, note: String) -> Diagnostic {
+        self.notes.push(note);
+        self
+    }
+
+    pub fn with_help(mut self, help: String) -> Diagnostic {
+        self.help = Some(help);
+        self
+    }
+
+    /// Renders the diagnostic with the line it points at and the span underlined:
+    ///
+    /// ```text
+    /// error: `x` is not defined.
+    ///  --> line 2, column 7
+    ///   |
+    /// 2 | print x + 1;
+    ///   |       ^ not defined
+    /// ```
+    pub fn render(&self, source: &str, color: bool) -> String {
+        self.render_at(&Location::new(source, self.span), color)
+    }
+
+    /// Renders the diagnostic at a location found earlier, for when the source is gone
+    pub(crate) fn render_at(&self, location: &Location, color: bool) -> String {
+        let paint = |style: &str, text: &str| {
+            if color {
+                format!("{}{}{}", style, text, RESET)
+            } else {
+                text.to_string()
+            }
+        };
+
+        let line_number = location.line.to_string();
+        let gutter = " ".repeat(line_number.len());
+        let pipe = paint(BLUE, "|");
+
+        // Tabs are kept in front of the underline so it lines up with the source line
+        let before: String = location
+            .source_line
+            .chars()
+            .take(location.column - 1)
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+        // Spans over several lines are only underlined up to the end of the first one
+        let line = &location.source_line;
+        let span_start = line
+            .char_indices()
+            .nth(location.column - 1)
+            .map_or(line.len(), |(index, _)| index);
+        let span_end = (span_start + self.span.end - self.span.start).min(line.len());
+        let span_length = line[span_start..span_end].chars().count();
+        let underline = "^".repeat(span_length.max(1));
+
+        let mut output = format!(
+            "{}{}\n{}{} {}\n{} {}\n{} {} {}\n{} {} {}{}",
+            paint(RED, "error"),
+            paint(BOLD, &format!(": {}", self.message)),
+            gutter,
+            paint(BLUE, "-->"),
+            location,
+            gutter,
+            pipe,
+            paint(BLUE, &line_number),
+            pipe,
+            location.source_line,
+            gutter,
+            pipe,
+            before,
+            paint(RED, &underline),
+        );
+        if let Some(label) = &self.label {
+            output += &format!(" {}", paint(RED, label));
+        }
+
+        for note in &self.notes {
+            output += &format!(
+                "\n{} {} {}: {}",
+                gutter,
+                paint(BLUE, "="),
+                paint(BOLD, "note"),
+                note
+            );
+        }
+        if let Some(help) = &self.help {
+            output += &format!(
+                "\n{} {} {}: {}",
+                gutter,
+                paint(BLUE, "="),
+                paint(BOLD, "help"),
+                help
+            );
+        }
+
+        output
+    }
+}
+
+/// Every problem found in a program, together with its source so they can be shown
+#[derive(Clone, Debug, PartialEq)]
+pub struct Diagnostics {
+    pub diagnostics: Vec<Diagnostic>,
+    pub source: String,
+}
+
+impl Diagnostics {
+    pub fn iter(&self) -> std::slice::Iter<'_, Diagnostic> {
+        self.diagnostics.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.diagnostics.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.diagnostics.is_empty()
+    }
+
+    /// Renders every diagnostic, with an empty line between them
+    pub fn render(&self, color: bool) -> String {
+        let rendered: Vec<String> = self
+            .diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.render(&self.source, color))
+            .collect();
+
+        rendered.join("\n\n")
+    }
+}
+
+impl fmt::Display for Diagnostics {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.render(false))
+    }
+}
+
+impl std::error::Error for Diagnostics {}
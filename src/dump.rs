@@ -0,0 +1,146 @@
+use crate::error::Location;
+use crate::value::Value;
+
+/// How `Interpreter::dump_state` writes the state
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DumpFormat {
+    /// Aligned and easy to read, one line per variable or procedure
+    Pretty,
+    /// A single JSON object, for other programs to read
+    Json,
+}
+
+/// A procedure or function as it is shown in the state dump
+pub(crate) struct ProcedureState<'a> {
+    pub name: &'a str,
+    pub params: &'a [String],
+    pub returns_value: bool,
+    pub location: Location,
+}
+
+/// Both lists have to be sorted by name already
+pub(crate) fn render(
+    variables: &[(&str, &Value)],
+    procedures: &[ProcedureState],
+    format: DumpFormat,
+) -> String {
+    match format {
+        DumpFormat::Pretty => pretty(variables, procedures),
+        DumpFormat::Json => json(variables, procedures),
+    }
+}
+
+fn pretty(variables: &[(&str, &Value)], procedures: &[ProcedureState]) -> String {
+    let mut output = String::from("Variables:");
+    if variables.is_empty() {
+        output += " none";
+    }
+    let name_width = variables.iter().map(|(name, _)| name.len()).max();
+    for (name, value) in variables {
+        output += &format!(
+            "\n    {:width$} : {:6} = {}",
+            name,
+            value.get_type().to_string(),
+            show_value(value),
+            width = name_width.unwrap_or(0)
+        );
+    }
+
+    output += "\n\nProcedures:";
+    if procedures.is_empty() {
+        output += " none";
+    }
+    for procedure in procedures {
+        output += &format!(
+            "\n    {} {}({}) at {}",
+            if procedure.returns_value {
+                "fn"
+            } else {
+                "proc"
+            },
+            procedure.name,
+            procedure.params.join(", "),
+            procedure.location
+        );
+    }
+
+    output
+}
+
+fn json(variables: &[(&str, &Value)], procedures: &[ProcedureState]) -> String {
+    let variables: Vec<String> = variables
+        .iter()
+        .map(|(name, value)| {
+            format!(
+                "{{\"name\":{},\"type\":{},\"value\":{}}}",
+                quote(name),
+                quote(&value.get_type().to_string()),
+                json_value(value)
+            )
+        })
+        .collect();
+
+    let procedures: Vec<String> = procedures
+        .iter()
+        .map(|procedure| {
+            let params: Vec<String> = procedure.params.iter().map(|param| quote(param)).collect();
+            format!(
+                "{{\"name\":{},\"kind\":{},\"params\":[{}],\"line\":{},\"column\":{}}}",
+                quote(procedure.name),
+                quote(if procedure.returns_value {
+                    "fn"
+                } else {
+                    "proc"
+                }),
+                params.join(","),
+                procedure.location.line,
+                procedure.location.column
+            )
+        })
+        .collect();
+
+    format!(
+        "{{\"variables\":[{}],\"procedures\":[{}]}}",
+        variables.join(","),
+        procedures.join(",")
+    )
+}
+
+/// Strings are quoted so that spaces and empty strings can be seen, and so they cannot be
+/// mistaken for numbers or booleans in error messages
+pub(crate) fn show_value(value: &Value) -> String {
+    match value {
+        Value::Str(string) => quote(string),
+        value => value.to_string(),
+    }
+}
+
+fn json_value(value: &Value) -> String {
+    match value {
+        Value::Num(num) if num.is_finite() => num.to_string(),
+        // JSON has no NaN or infinity
+        Value::Num(_) | Value::None => "null".to_string(),
+        Value::Str(string) => quote(string),
+        Value::Bool(value) => value.to_string(),
+    }
+}
+
+/// A string literal with quotes and escapes, the way JSON writes it
+fn quote(string: &str) -> String {
+    let mut quoted = String::from("\"");
+
+    for c in string.chars() {
+        match c {
+            '"' => quoted += "\\\"",
+            '\\' => quoted += "\\\\",
+            '\n' => quoted += "\\n",
+            '\t' => quoted += "\\t",
+            '\r' => quoted += "\\r",
+            c if (c as u32) < 0x20 => quoted += &format!("\\u{:04x}", c as u32),
+            c => quoted.push(c),
+        }
+    }
+
+    quoted.push('"');
+    quoted
+}
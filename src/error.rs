@@ -0,0 +1,176 @@
+use crate::ast::Span;
+use crate::diagnostics::Diagnostic;
+use std::fmt;
+
+/// What went wrong while running a program
+#[derive(Clone, Debug, PartialEq)]
+pub enum ErrorKind {
+    /// `break`, `continue` or `return` used where they are not allowed
+    ControlFlow,
+    /// A variable, procedure or function that does not exist
+    Undefined,
+    /// A value of the wrong type
+    Type,
+    /// A procedure called with the wrong number of arguments
+    Arity,
+    /// A procedure used inside an expression, or a function that did not return a value
+    MissingReturnValue,
+    /// Too many procedure calls running at the same time, or too little stack left for them
+    RecursionLimit,
+    /// A procedure defined in a way that cannot work, like two parameters with the same name
+    InvalidDefinition,
+    /// A value that has the right type but cannot be used, like a `for` step of 0
+    InvalidValue,
+    /// A function registered from Rust failed
+    Native,
+    /// Writing the output failed
+    Io,
+    /// The program went over one of the limits set in `Options`
+    LimitExceeded(Limit),
+    /// The program was stopped through its `CancelToken`
+    Cancelled,
+}
+
+/// The limits a program can be stopped by
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Limit {
+    /// `max_steps`, how many statements and loop iterations can run
+    Steps,
+    /// `max_memory`, how many bytes of strings can be held
+    Memory,
+    /// `timeout`, how long the program can run
+    Time,
+}
+
+/// Where in the source an error happened, lines and columns start at 1
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Location {
+    pub span: Span,
+    pub line: usize,
+    pub column: usize,
+    // The whole line the error starts on
+    pub source_line: String,
+}
+
+impl Location {
+    pub fn new(source: &str, span: Span) -> Location {
+        let before = &source[..span.start];
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+        let line_end = source[span.start..]
+            .find('\n')
+            .map_or(source.len(), |index| span.start + index);
+
+        Location {
+            span,
+            line: before.matches('\n').count() + 1,
+            column: source[line_start..span.start].chars().count() + 1,
+            source_line: source[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+/// A procedure that was running when an error happened
+#[derive(Clone, Debug, PartialEq)]
+pub struct CallFrame {
+    pub name: String,
+    // Where the procedure was called from
+    pub location: Location,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RuntimeError {
+    pub kind: ErrorKind,
+    pub message: String,
+    // Boxed to keep the error small, it is carried through every `Result` in the interpreter
+    pub location: Box<Location>,
+    // Short text shown under the code the error points at
+    pub label: Option<String>,
+    pub help: Option<String>,
+    // Innermost call first
+    pub call_stack: Vec<CallFrame>,
+}
+
+impl RuntimeError {
+    /// An error for functions registered from Rust to give back, the interpreter
+    /// fills in where it happened
+    pub fn new(kind: ErrorKind, message: String) -> RuntimeError {
+        RuntimeError {
+            kind,
+            message,
+            location: Box::default(),
+            label: None,
+            help: None,
+            call_stack: vec![],
+        }
+    }
+
+    pub fn with_label(mut self, label: String) -> RuntimeError {
+        self.label = Some(label);
+        self
+    }
+
+    pub fn with_help(mut self, help: String) -> RuntimeError {
+        self.help = Some(help);
+        self
+    }
+
+    /// Calls that were running, collapsing the repeats of deep recursion
+    fn call_stack_lines(&self) -> Vec<String> {
+        let mut lines = vec![];
+
+        let mut frames = self.call_stack.iter().peekable();
+        while let Some(frame) = frames.next() {
+            let mut repeats = 1;
+            while frames.peek() == Some(&frame) {
+                frames.next();
+                repeats += 1;
+            }
+
+            let mut line = format!("`{}` was called from {}", frame.name, frame.location);
+            if repeats > 1 {
+                line += &format!(", {} times in a row", repeats);
+            }
+            lines.push(line);
+        }
+
+        lines
+    }
+
+    /// The call stack becomes notes, the most recent call first
+    pub fn to_diagnostic(&self) -> Diagnostic {
+        let mut diagnostic = Diagnostic::new(self.message.clone(), self.location.span);
+        diagnostic.label = self.label.clone();
+        diagnostic.notes = self.call_stack_lines();
+        diagnostic.help = self.help.clone();
+
+        diagnostic
+    }
+
+    /// Renders the error the way `Diagnostic::render` does, from the source line it
+    /// happened on. Errors in procedures from earlier runs point into their own source,
+    /// so this works where rendering against the current program would not.
+    pub fn render(&self, color: bool) -> String {
+        self.to_diagnostic().render_at(&self.location, color)
+    }
+}
+
+impl fmt::Display for RuntimeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.location, self.message)?;
+        for line in self.call_stack_lines() {
+            write!(f, "\n    {}", line)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for RuntimeError {}
@@ -0,0 +1,67 @@
+//! Auctan, a simple hobby language
+//!
+//! ```
+//! let program = auctan::parse("x = 1 + 2;").unwrap();
+//!
+//! let mut interpreter = auctan::Interpreter::new();
+//! interpreter.run(&program).unwrap();
+//! ```
+
+#[macro_use]
+extern crate lalrpop_util;
+
+pub mod ast;
+pub mod cancel;
+pub mod diagnostics;
+pub mod dump;
+pub mod error;
+pub mod value;
+
+mod compiler;
+lalrpop_mod!(#[allow(clippy::all)] parser);
+mod syntax_error;
+
+pub use ast::Type;
+pub use cancel::CancelToken;
+pub use compiler::{Interpreter, Options, DEFAULT_MAX_CALL_DEPTH, DEFAULT_MAX_STACK_BYTES};
+pub use diagnostics::{Diagnostic, Diagnostics};
+pub use dump::DumpFormat;
+pub use error::{ErrorKind, Limit, RuntimeError};
+pub use value::Value;
+
+use ast::Node;
+
+/// A program that parsed without mistakes and can be run. Only `parse` makes one, so its
+/// spans always point into its own source.
+#[derive(Clone, Debug)]
+pub struct Program {
+    statements: Vec<Node>,
+    source: String,
+}
+
+impl Program {
+    pub fn statements(&self) -> &[Node] {
+        &self.statements
+    }
+
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+}
+
+/// Parses a whole program, giving back every syntax error when there is any
+pub fn parse(source: &str) -> Result<Program, Diagnostics> {
+    let (statements, diagnostics) = syntax_error::parse(source);
+
+    if diagnostics.is_empty() {
+        Ok(Program {
+            statements,
+            source: source.to_string(),
+        })
+    } else {
+        Err(Diagnostics {
+            diagnostics,
+            source: source.to_string(),
+        })
+    }
+}
@@ -1,28 +1,124 @@
 #![allow(warnings, dead_code, unused_must_use)]
-#![feature(box_into_inner)]
+use auctan::{DumpFormat, Interpreter, Options};
 use std::env::args;
 use std::fs::{self};
-use std::process::Command;
-pub mod ast;
-mod compiler;
-mod parser;
+use std::io::IsTerminal;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Generous estimate of the stack a single procedure call needs in a debug build
+const STACK_BYTES_PER_CALL: usize = 128 * 1024;
+/// The most stack the interpreter thread asks for, which caps `--max-depth`
+const MAX_STACK_BYTES: usize = 1024 * 1024 * 1024;
+/// Stack left over for everything that is not a procedure call
+const EXTRA_STACK_BYTES: usize = 8 * 1024 * 1024;
 
 fn main() {
-    let mut args = args();
+    let mut input_file = None;
+    let mut options = Options::default();
+    let mut dump_format = None;
 
-    args.next().unwrap();
+    for arg in args().skip(1) {
+        if let Some(depth) = arg.strip_prefix("--max-depth=") {
+            let max_depth = MAX_STACK_BYTES / STACK_BYTES_PER_CALL;
+            match depth.parse::<usize>() {
+                Ok(depth) if depth <= max_depth => options.max_call_depth = depth,
+                _ => {
+                    println!(
+                        "Error: `{}` is not a valid maximum call depth, it can be at most {}",
+                        depth, max_depth
+                    );
+                    std::process::exit(1);
+                }
+            }
+        } else if let Some(steps) = arg.strip_prefix("--max-steps=") {
+            options.max_steps = Some(parse_number(steps, "maximum number of steps"));
+        } else if let Some(bytes) = arg.strip_prefix("--max-memory=") {
+            options.max_memory = Some(parse_number(bytes, "maximum number of bytes"));
+        } else if let Some(seconds) = arg.strip_prefix("--timeout=") {
+            match Duration::try_from_secs_f64(parse_number(seconds, "number of seconds")) {
+                Ok(timeout) => options.timeout = Some(timeout),
+                Err(_) => {
+                    println!("Error: `{}` is not a valid number of seconds", seconds);
+                    std::process::exit(1);
+                }
+            }
+        } else if arg == "--strict" {
+            options.strict_conditions = true;
+        } else if arg == "--dump-state" {
+            dump_format = Some(DumpFormat::Pretty);
+        } else if let Some(format) = arg.strip_prefix("--dump-state=") {
+            match format {
+                "pretty" => dump_format = Some(DumpFormat::Pretty),
+                "json" => dump_format = Some(DumpFormat::Json),
+                _ => {
+                    println!(
+                        "Error: `{}` is not a state dump format, use `pretty` or `json`",
+                        format
+                    );
+                    std::process::exit(1);
+                }
+            }
+        } else {
+            input_file = Some(arg);
+        }
+    }
 
-    let input_file = args.next().unwrap();
+    let input_file = input_file.unwrap();
 
     let code = fs::read_to_string(input_file.clone()).unwrap();
-    match parser::ProgramParser::new().parse(&code) {
-        Ok(ast) => {
-            compiler::Interpreter::interpret(code.lines().map(String::from).collect(), ast);
-            // println!("{}", out);
+    let color = std::io::stdout().is_terminal();
+
+    let program = match auctan::parse(&code) {
+        Ok(program) => program,
+        Err(syntax_errors) => {
+            println!("{}", syntax_errors.render(color));
+            std::process::exit(2);
+        }
+    };
+
+    // Every procedure call nests a few Rust calls, so the interpreter runs on a thread
+    // with enough stack to reach the recursion limit.
+    options.max_stack_bytes = options
+        .max_call_depth
+        .checked_mul(STACK_BYTES_PER_CALL)
+        .map_or(MAX_STACK_BYTES, |bytes| bytes.min(MAX_STACK_BYTES))
+        .max(options.max_stack_bytes);
+    let interpreter = std::thread::Builder::new()
+        .stack_size(options.max_stack_bytes + EXTRA_STACK_BYTES)
+        .spawn(move || {
+            let mut interpreter = Interpreter::with_options(options);
+            let result = interpreter.run(&program);
+            let dump = dump_format.map(|format| interpreter.dump_state(format));
+            (result, dump)
+        });
+    let (result, dump) = match interpreter {
+        Ok(interpreter) => interpreter.join().unwrap(),
+        Err(error) => {
+            println!("Error: the interpreter could not be started: {}", error);
+            std::process::exit(1);
         }
-        Err(e) => {
-            // let e = e.to_string().replace("r#\"[a-zA-Z_][a-zA-Z_0-9]*\"#", "a name");
-            println!("Error: {}", e);
+    };
+
+    if let Err(error) = &result {
+        println!("{}", error.render(color));
+    }
+    // Shown after errors too, it tells how far the program got
+    if let Some(dump) = dump {
+        println!("\n{}", dump);
+    }
+    if result.is_err() {
+        std::process::exit(1);
+    }
+}
+
+/// Parses the value of a command line option, stopping with an error when it is not valid
+fn parse_number<T: FromStr>(value: &str, what: &str) -> T {
+    match value.parse() {
+        Ok(number) => number,
+        Err(_) => {
+            println!("Error: `{}` is not a valid {}", value, what);
+            std::process::exit(1);
         }
     }
 }
@@ -0,0 +1,388 @@
+use crate::ast::{Node, NodeKind, Span, MAX_NESTING};
+use crate::diagnostics::Diagnostic;
+use crate::parser::{ProgramParser, Token};
+use lalrpop_util::ParseError;
+
+/// Tokens that can start an expression, listed together as "an expression"
+const EXPRESSION_START: &[&str] = &[
+    "!",
+    "(",
+    "+",
+    "-",
+    "true",
+    "false",
+    "a number",
+    "a string",
+    "an identifier",
+];
+
+/// Tokens that can start a statement, listed together as "a statement"
+const STATEMENT_START: &[&str] = &[
+    "{",
+    "break",
+    "call",
+    "continue",
+    "fn",
+    "for",
+    "if",
+    "let",
+    "print",
+    "proc",
+    "return",
+    "while",
+    "a comment",
+    "an identifier",
+];
+
+/// Operators that go between two values, listed together as "an operator"
+const BINARY_OPERATORS: &[&str] = &[
+    "+", "-", "*", "/", "%", "**", "==", "!=", ">", "<", ">=", "<=", "&&", "||",
+];
+
+// Longest first, so `..=` is not mistaken for `=`
+const OPERATORS: &[&str] = &["..=", "==", "!=", ">=", "<=", "&&", "||", "**", ".."];
+
+/// Parses the program, carrying on after syntax errors so all of them are reported at once.
+/// Gives back the partial AST, where code that could not be parsed is `NodeKind::Error`
+/// or `NodeKind::TooDeep`, and a diagnostic for every mistake.
+pub fn parse(source: &str) -> (Vec<Node>, Vec<Diagnostic>) {
+    let mut errors = vec![];
+    let result = ProgramParser::new().parse(&mut errors, source);
+
+    let mut diagnostics: Vec<Diagnostic> = errors
+        .iter()
+        .map(|recovery| to_diagnostic(source, &recovery.error))
+        .collect();
+    let ast = match result {
+        Ok(ast) => ast,
+        // The parser could not recover, so there is nothing left to give back
+        Err(error) => {
+            diagnostics.push(to_diagnostic(source, &error));
+            vec![]
+        }
+    };
+
+    for node in &ast {
+        find_too_deep(node, &mut diagnostics);
+    }
+    diagnostics.sort_by_key(|diagnostic| diagnostic.span.start);
+
+    (ast, diagnostics)
+}
+
+/// Reports the code that is nested too deeply, the tree is only `MAX_NESTING` deep so
+/// this can recurse
+fn find_too_deep(node: &Node, diagnostics: &mut Vec<Diagnostic>) {
+    if let NodeKind::TooDeep(span) = node.kind {
+        diagnostics.push(
+            Diagnostic::new(
+                format!("code is nested more than {} levels deep", MAX_NESTING),
+                span,
+            )
+            .with_label("nested too deeply".to_string())
+            .with_help("move parts of it into variables or functions".to_string()),
+        );
+    }
+
+    for child in node.kind.children() {
+        find_too_deep(child, diagnostics);
+    }
+}
+
+/// Turns an error from the parser into a message that names tokens the way a person would
+pub fn to_diagnostic(source: &str, error: &ParseError<usize, Token, &str>) -> Diagnostic {
+    match error {
+        // A string that is never closed runs to the end of the file, which no token matches
+        ParseError::InvalidToken { location } if source[*location..].starts_with('"') => {
+            let line_end = source[*location..]
+                .find('\n')
+                .map_or(source.len(), |index| *location + index);
+            Diagnostic::new(
+                "unterminated string".to_string(),
+                Span {
+                    start: *location,
+                    end: line_end,
+                },
+            )
+            .with_label("this string is never closed".to_string())
+            .with_help("add a `\"` where the string should end".to_string())
+        }
+        ParseError::InvalidToken { location } => {
+            let character = source[*location..].chars().next().unwrap();
+            Diagnostic::new(
+                format!("`{}` is not a valid character here", character),
+                Span {
+                    start: *location,
+                    end: *location + character.len_utf8(),
+                },
+            )
+            .with_label("not part of any token".to_string())
+        }
+        ParseError::UnrecognizedEOF { location, expected } => Diagnostic::new(
+            expected_message(source, *location, expected),
+            Span {
+                start: *location,
+                end: *location,
+            },
+        )
+        .with_label("the file ends here".to_string()),
+        ParseError::UnrecognizedToken {
+            token: (start, Token(_, text), end),
+            expected,
+        } => Diagnostic::new(
+            expected_message(source, *start, expected),
+            Span {
+                start: *start,
+                end: *end,
+            },
+        )
+        .with_label(format!("found `{}`", text)),
+        ParseError::ExtraToken {
+            token: (start, Token(_, text), end),
+        } => Diagnostic::new(
+            format!("unexpected `{}`", text),
+            Span {
+                start: *start,
+                end: *end,
+            },
+        )
+        .with_label("nothing can follow this".to_string()),
+        ParseError::User { error } => Diagnostic::new(
+            error.to_string(),
+            Span {
+                start: source.len(),
+                end: source.len(),
+            },
+        ),
+    }
+}
+
+/// "expected an expression after `print`", naming the token before `location` when there is one
+fn expected_message(source: &str, location: usize, expected: &[String]) -> String {
+    let parser = ProgramParser::new();
+    let statement = &source[statement_start(source, location)..location];
+    let names: Vec<&str> = expected
+        .iter()
+        .map(|token| token_name(token))
+        .filter(|name| fits(&parser, statement, name))
+        .collect();
+
+    let mut message = match join(&group(names)) {
+        Some(list) => format!("expected {}", list),
+        None => "unexpected token".to_string(),
+    };
+    if let Some(previous) = previous_token(source, location) {
+        message += &format!(" after {}", previous);
+    }
+
+    message
+}
+
+/// lalrpop names fixed tokens with their quoted text and the others with their regex
+fn token_name(token: &str) -> &str {
+    if token.starts_with("r#") {
+        if token.contains("//") {
+            "a comment"
+        } else if token.contains("a-zA-Z") {
+            "an identifier"
+        } else if token.contains("0-9") {
+            "a number"
+        } else {
+            "a string"
+        }
+    } else {
+        token.trim_matches('"')
+    }
+}
+
+/// The parser lists every token its state could shift, some of which only fit in other
+/// places that share the state. A token really fits if the statement with the mistake
+/// parses past it once it is put in front of the token that failed.
+fn fits(parser: &ProgramParser, statement: &str, name: &str) -> bool {
+    let sample = match name {
+        "a comment" => "//",
+        "an identifier" => "x",
+        "a number" => "0",
+        "a string" => "\"\"",
+        token => token,
+    };
+    // The rest of the source is left out, so mistakes after the token do not get in the way
+    let candidate = format!("{} {}", statement, sample);
+    let sample_end = candidate.len();
+
+    let mut errors = vec![];
+    let result = parser.parse(&mut errors, &candidate);
+
+    // Mistakes earlier in the statement are still there, only the first one from here on counts
+    errors
+        .into_iter()
+        .map(|recovery| recovery.error)
+        .chain(result.err())
+        .filter_map(|error| error_location(&error))
+        .filter(|error_location| *error_location >= statement.len())
+        .all(|error_location| error_location >= sample_end)
+}
+
+/// Where the statement that `location` is in starts, right after the last `;`, `{` or `}`.
+/// Statements inside a block parse the same as at the top level, so `fits` only has to
+/// parse this one again instead of the whole program.
+fn statement_start(source: &str, location: usize) -> usize {
+    let mut start = 0;
+    // Where the statements that opened the surrounding blocks start
+    let mut blocks = vec![];
+    // Where the statement that the last block belongs to starts
+    let mut before_block = 0;
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut in_comment = false;
+
+    for (index, c) in source[..location].char_indices() {
+        match c {
+            '\n' if in_comment => in_comment = false,
+            _ if in_comment => {}
+            _ if escaped => escaped = false,
+            '\\' if in_string => escaped = true,
+            '"' => in_string = !in_string,
+            _ if in_string => {}
+            '/' if source[index + 1..].starts_with('/') => in_comment = true,
+            ';' => start = index + 1,
+            '{' => {
+                blocks.push(start);
+                start = index + 1;
+            }
+            '}' => {
+                before_block = blocks.pop().unwrap_or(0);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+
+    // `else` carries on the `if` before it
+    if source[start..location].trim_start().starts_with("else") {
+        before_block
+    } else {
+        start
+    }
+}
+
+fn error_location(error: &ParseError<usize, Token, &str>) -> Option<usize> {
+    match error {
+        ParseError::InvalidToken { location } => Some(*location),
+        ParseError::UnrecognizedEOF { location, .. } => Some(*location),
+        ParseError::UnrecognizedToken {
+            token: (start, _, _),
+            ..
+        } => Some(*start),
+        ParseError::ExtraToken {
+            token: (start, _, _),
+        } => Some(*start),
+        ParseError::User { .. } => None,
+    }
+}
+
+/// Replaces whole groups of tokens with the name of what they start
+fn group(mut names: Vec<&str>) -> Vec<String> {
+    let mut grouped = vec![];
+
+    for (group, members) in &[
+        ("a statement", STATEMENT_START),
+        ("an expression", EXPRESSION_START),
+    ] {
+        if members.iter().all(|member| names.contains(member)) {
+            names.retain(|name| !members.contains(name));
+            grouped.push(group.to_string());
+        }
+    }
+
+    // Operators are only named one by one when there are few of them
+    if names
+        .iter()
+        .filter(|name| BINARY_OPERATORS.contains(name))
+        .count()
+        > 2
+    {
+        names.retain(|name| !BINARY_OPERATORS.contains(name));
+        grouped.push("an operator".to_string());
+    }
+
+    for name in names {
+        if name.starts_with("a ") || name.starts_with("an ") {
+            grouped.push(name.to_string());
+        } else {
+            grouped.push(format!("`{}`", name));
+        }
+    }
+
+    grouped
+}
+
+/// "a, b or c"
+fn join(names: &[String]) -> Option<String> {
+    match names {
+        [] => None,
+        [name] => Some(name.clone()),
+        [rest @ .., last] => Some(format!("{} or {}", rest.join(", "), last)),
+    }
+}
+
+/// Finds the token that ends right before `location` by looking backwards through the source
+fn previous_token(source: &str, location: usize) -> Option<String> {
+    let before = source[..location].trim_end();
+    let last_line = &before[before.rfind('\n').map_or(0, |index| index + 1)..];
+
+    if find_comment(last_line).is_some() {
+        return Some("a comment".to_string());
+    }
+
+    let last = before.chars().last()?;
+    if last == '"' {
+        return Some("a string".to_string());
+    }
+    if last.is_alphanumeric() || last == '_' {
+        let start = before
+            .rfind(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .map_or(0, |index| index + 1);
+        let mut word = &before[start..];
+
+        // The whole of a decimal number, not only the part after the dot
+        let prefix = &before[..start];
+        if word.chars().all(|c| c.is_ascii_digit())
+            && prefix.ends_with('.')
+            && !prefix.ends_with("..")
+        {
+            let integer = prefix[..start - 1].trim_end_matches(|c: char| c.is_ascii_digit());
+            if integer.len() < start - 1 {
+                word = &before[integer.len()..];
+            }
+        }
+
+        return Some(format!("`{}`", word));
+    }
+
+    let operator = OPERATORS
+        .iter()
+        .find(|operator| before.ends_with(*operator))
+        .map_or(&before[before.len() - last.len_utf8()..], |operator| {
+            operator
+        });
+    Some(format!("`{}`", operator))
+}
+
+/// Where a `//` comment starts on the line, ignoring `//` inside strings
+fn find_comment(line: &str) -> Option<usize> {
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for (index, c) in line.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_string => escaped = true,
+            '"' => in_string = !in_string,
+            '/' if !in_string && line[index + 1..].starts_with('/') => return Some(index),
+            _ => {}
+        }
+    }
+
+    None
+}
@@ -0,0 +1,34 @@
+use crate::ast::Type;
+use std::fmt;
+
+/// A value while the program is running
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    Num(f64),
+    Str(String),
+    Bool(bool),
+    None,
+}
+
+impl Value {
+    pub fn get_type(&self) -> Type {
+        match self {
+            Value::Num(_) => Type::Num,
+            Value::Str(_) => Type::Str,
+            Value::Bool(_) => Type::Bool,
+            Value::None => Type::None,
+        }
+    }
+}
+
+/// How a value shows up when it is printed
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Value::Num(num) => write!(f, "{}", num),
+            Value::Str(string) => write!(f, "{}", string),
+            Value::Bool(value) => write!(f, "{}", value),
+            Value::None => write!(f, "none"),
+        }
+    }
+}
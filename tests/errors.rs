@@ -0,0 +1,220 @@
+use std::fs;
+use std::process::Command;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static SCRIPT_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+/// Runs the script through the interpreter and gives back what it printed and its exit code
+fn run(code: &str) -> (String, i32) {
+    run_with_args(code, &[])
+}
+
+fn run_with_args(code: &str, args: &[&str]) -> (String, i32) {
+    let script = std::env::temp_dir().join(format!(
+        "auctan_error_{}_{}.au",
+        std::process::id(),
+        SCRIPT_COUNT.fetch_add(1, Ordering::SeqCst)
+    ));
+    fs::write(&script, code).unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_auctan"))
+        .args(args)
+        .arg(&script)
+        .output()
+        .unwrap();
+    fs::remove_file(&script).unwrap();
+
+    (
+        String::from_utf8(output.stdout).unwrap(),
+        output.status.code().unwrap(),
+    )
+}
+
+#[test]
+fn runtime_errors_underline_the_code_that_failed() {
+    let (output, code) = run("// Adds things\nx = 1;\nprint x + \"a\";\n");
+
+    assert_eq!(code, 1);
+    assert_eq!(
+        output,
+        "error: `1` and `\"a\"` are not the same type.\n \
+         --> line 3, column 7\n  \
+         |\n\
+         3 | print x + \"a\";\n  \
+         |       ^^^^^^^ number and string\n"
+    );
+}
+
+#[test]
+fn runtime_errors_inside_procedures_list_the_calls() {
+    let (output, _) = run("proc greet(name) {\n    print nme;\n}\ncall greet(\"Ana\");\n");
+
+    assert_eq!(
+        output,
+        "error: `nme` is not defined.\n \
+         --> line 2, column 11\n  \
+         |\n\
+         2 |     print nme;\n  \
+         |           ^^^ not defined\n  \
+         = note: `greet` was called from line 4, column 1\n"
+    );
+}
+
+#[test]
+fn syntax_errors_name_the_tokens_that_were_expected() {
+    let (output, code) = run("x = 1;\nprint ;\n");
+
+    assert_eq!(code, 2);
+    assert_eq!(
+        output,
+        "error: expected an expression after `print`\n \
+         --> line 2, column 7\n  \
+         |\n\
+         2 | print ;\n  \
+         |       ^ found `;`\n"
+    );
+}
+
+#[test]
+fn every_syntax_error_is_reported() {
+    let (output, code) =
+        run("x = 1 +;\nprint \"ok\";\nwhile (x > ) {\n    print x;\n}\nlet = 5;\n");
+
+    assert_eq!(code, 2);
+    let messages: Vec<&str> = output
+        .lines()
+        .filter(|line| line.starts_with("error:"))
+        .collect();
+    assert_eq!(
+        messages,
+        [
+            "error: expected an expression after `+`",
+            "error: expected an expression after `>`",
+            "error: expected an identifier after `let`",
+        ]
+    );
+}
+
+#[test]
+fn misplaced_control_flow_is_rejected_before_anything_runs() {
+    let (output, code) = run("print \"start\";\nif (true) {\n    break;\n}\n");
+
+    assert_eq!(code, 1);
+    assert_eq!(
+        output,
+        "error: `break` can only be used inside a loop.\n \
+         --> line 3, column 5\n  \
+         |\n\
+         3 |     break;\n  \
+         |     ^^^^^^ not inside a loop\n"
+    );
+
+    let (output, _) = run("proc p {\n    return 1;\n}\n");
+    assert!(output.starts_with("error: Procedures cannot return a value.\n"));
+
+    let (output, _) = run("x = 1;\nreturn;\n");
+    assert!(
+        output.starts_with("error: `return` can only be used inside a procedure or function.\n")
+    );
+}
+
+#[test]
+fn runaway_recursion_stops_at_the_depth_limit() {
+    let (output, code) = run_with_args(
+        "fn f(n) {\n    return f(n + 1);\n}\nprint f(0);\n",
+        &["--max-depth=50"],
+    );
+
+    assert_eq!(code, 1);
+    assert_eq!(
+        output,
+        "error: Recursion limit exceeded, `f` was called while 50 procedures were already running.\n \
+         --> line 2, column 12\n  \
+         |\n\
+         2 |     return f(n + 1);\n  \
+         |            ^^^^^^^^\n  \
+         = note: `f` was called from line 2, column 12, 49 times in a row\n  \
+         = note: `f` was called from line 4, column 7\n  \
+         = help: raise the limit with `--max-depth=N` if the recursion is expected\n"
+    );
+}
+
+#[test]
+fn parameters_declared_twice_are_rejected_before_anything_runs() {
+    let (output, code) = run("print \"start\";\nproc add(a, b, a) {\n    print a;\n}\n");
+
+    assert_eq!(code, 1);
+    assert_eq!(
+        output,
+        "error: Parameter `a` is declared twice.\n \
+         --> line 2, column 16\n  \
+         |\n\
+         2 | proc add(a, b, a) {\n  \
+         |                ^ declared again here\n"
+    );
+}
+
+#[test]
+fn procedures_called_with_the_wrong_number_of_arguments_say_how_many_they_expect() {
+    let (output, code) =
+        run("proc greet(name) {\n    print name;\n}\ncall greet(\"Ana\", \"Bob\");\n");
+
+    assert_eq!(code, 1);
+    assert_eq!(
+        output,
+        "error: `greet` expects 1 parameter(s) but was called with 2 argument(s).\n \
+         --> line 4, column 1\n  \
+         |\n\
+         4 | call greet(\"Ana\", \"Bob\");\n  \
+         | ^^^^^^^^^^^^^^^^^^^^^^^^^ called with 2 argument(s)\n"
+    );
+}
+
+#[test]
+fn procedures_used_as_values_suggest_calling_them() {
+    let (output, code) = run("proc g(a) {\n    print a;\n}\nx = 1 + g(1);\n");
+
+    assert_eq!(code, 1);
+    assert_eq!(
+        output,
+        "error: `g` is a procedure and does not return a value, so it cannot be used in an expression.\n \
+         --> line 4, column 9\n  \
+         |\n\
+         4 | x = 1 + g(1);\n  \
+         |         ^ defined with `proc`\n  \
+         = help: run it on its own with `call g(1);`\n"
+    );
+}
+
+#[test]
+fn functions_that_end_without_return_point_at_their_end() {
+    let (output, code) =
+        run("fn f(n) {\n    if (n > 0) {\n        return n;\n    }\n}\nprint f(1);\nprint f(0);\n");
+
+    assert_eq!(code, 1);
+    assert_eq!(
+        output,
+        "1error: Function `f` finished without returning a value.\n \
+         --> line 5, column 1\n  \
+         |\n\
+         5 | }\n  \
+         | ^ ends here without `return`\n  \
+         = note: `f` was called from line 7, column 7\n"
+    );
+}
+
+#[test]
+fn unterminated_strings_are_named() {
+    let (output, code) = run("x = \"abc;\nprint 1;\n");
+
+    assert_eq!(code, 2);
+    assert_eq!(
+        output,
+        "error: unterminated string\n \
+         --> line 1, column 5\n  \
+         |\n\
+         1 | x = \"abc;\n  \
+         |     ^^^^^ this string is never closed\n  \
+         = help: add a `\"` where the string should end\n"
+    );
+}
@@ -0,0 +1,136 @@
+use auctan::{Interpreter, Options};
+
+/// Runs the script and gives back exactly what it printed, or the errors it stopped on
+fn run(code: &str) -> String {
+    let program = match auctan::parse(code) {
+        Ok(program) => program,
+        Err(errors) => return errors.render(false),
+    };
+
+    let mut output = vec![];
+    let result = Interpreter::with_io(Options::default(), &mut output, &b""[..]).run(&program);
+    if let Err(error) = result {
+        return error.render(false);
+    }
+
+    String::from_utf8(output).unwrap()
+}
+
+/// Prints a single expression and gives back the result
+fn eval(expr: &str) -> String {
+    run(&format!("print {};", expr))
+}
+
+#[test]
+fn subtraction_and_division_are_left_associative() {
+    assert_eq!(eval("10 - 2 - 3"), "5");
+    assert_eq!(eval("100 / 10 / 5"), "2");
+    assert_eq!(eval("8 / 4 * 2"), "4");
+    assert_eq!(eval("10 - 2 + 3"), "11");
+}
+
+#[test]
+fn multiplication_binds_tighter_than_addition() {
+    assert_eq!(eval("1 + 2 * 3"), "7");
+    assert_eq!(eval("2 * 3 + 4 * 5"), "26");
+    assert_eq!(eval("10 - 2 * 3 - 1"), "3");
+    assert_eq!(eval("(1 + 2) * 3"), "9");
+}
+
+#[test]
+fn comparisons_bind_looser_than_arithmetic() {
+    assert_eq!(eval("1 + 2 == 3"), "true");
+    assert_eq!(eval("2 * 3 > 5"), "true");
+    assert_eq!(eval("10 - 2 - 3 <= 4"), "false");
+}
+
+#[test]
+fn strings_are_ordered_alphabetically() {
+    assert_eq!(eval("\"a\" < \"b\""), "true");
+    assert_eq!(eval("\"b\" >= \"a\""), "true");
+    assert_eq!(eval("\"a\" <= \"a\""), "true");
+    assert_eq!(eval("\"b\" <= \"a\""), "false");
+    assert_eq!(eval("\"Ana\" > \"Bob\""), "false");
+}
+
+#[test]
+fn only_equality_works_across_types() {
+    assert_eq!(eval("1 == \"1\""), "false");
+    assert_eq!(eval("1 != \"1\""), "true");
+    assert!(eval("1 < \"1\"")
+        .starts_with("error: `1` and `\"1\"` are not the same type, so they cannot be compared."));
+    assert!(eval("\"a\" >= true").starts_with("error: `\"a\"` and `true` are not the same type"));
+}
+
+#[test]
+fn logical_operators_bind_loosest() {
+    assert_eq!(eval("2 * 3 > 5 && 1 < 2"), "true");
+    assert_eq!(eval("true || false && false"), "true");
+    assert_eq!(eval("false && false || true"), "true");
+    assert_eq!(eval("!false && false"), "false");
+    assert_eq!(eval("!(1 > 2)"), "true");
+}
+
+#[test]
+fn logical_operators_skip_the_right_side_once_the_result_is_known() {
+    assert_eq!(eval("false && undefined_name"), "false");
+    assert_eq!(eval("true || undefined_name"), "true");
+    assert!(eval("true && undefined_name").starts_with("error: `undefined_name` is not defined."));
+    assert!(eval("false || undefined_name").starts_with("error: `undefined_name` is not defined."));
+}
+
+#[test]
+fn strings_are_concatenated_from_the_left() {
+    assert_eq!(eval("\"a\" + \"b\" + \"c\""), "abc");
+}
+
+#[test]
+fn comparisons_cannot_be_chained() {
+    assert!(eval("1 < 2 < 3").starts_with("error: expected an operator or `;` after `2`"));
+}
+
+#[test]
+fn unary_minus_negates_literals_identifiers_and_groups() {
+    assert_eq!(eval("-5"), "-5");
+    assert_eq!(eval("-5 + 3"), "-2");
+    assert_eq!(eval("10 - -2"), "12");
+    assert_eq!(eval("-(2 + 3)"), "-5");
+    assert_eq!(eval("2 * -3"), "-6");
+    assert_eq!(eval("--4"), "4");
+    assert_eq!(run("x = 7; print -x;"), "-7");
+}
+
+#[test]
+fn unary_plus_keeps_numbers_as_they_are() {
+    assert_eq!(eval("+4"), "4");
+    assert_eq!(eval("+(1 - 3)"), "-2");
+    assert!(eval("+\"text\"").contains("is not a number"));
+}
+
+#[test]
+fn modulo_takes_the_sign_of_the_right_side() {
+    assert_eq!(eval("7 % 3"), "1");
+    assert_eq!(eval("-7 % 3"), "2");
+    assert_eq!(eval("7 % -3"), "-2");
+    assert_eq!(eval("-7 % -3"), "-1");
+    assert_eq!(eval("7.5 % 2"), "1.5");
+    assert_eq!(eval("1 + 7 % 4 * 2"), "7");
+}
+
+#[test]
+fn exponent_is_right_associative_and_binds_tighter_than_unary_minus() {
+    assert_eq!(eval("2 ** 10"), "1024");
+    assert_eq!(eval("2 ** 3 ** 2"), "512");
+    assert_eq!(eval("-2 ** 2"), "-4");
+    assert_eq!(eval("(-2) ** 2"), "4");
+    assert_eq!(eval("2 ** -1"), "0.5");
+    assert_eq!(eval("3 * 2 ** 2"), "12");
+}
+
+#[test]
+fn functions_can_call_themselves() {
+    assert_eq!(
+        run("fn factorial(n) {\n    if (n <= 1) {\n        return 1;\n    }\n    return n * factorial(n - 1);\n}\nprint factorial(10);"),
+        "3628800"
+    );
+}
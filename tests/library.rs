@@ -0,0 +1,438 @@
+use auctan::{DumpFormat, ErrorKind, Interpreter, Limit, Options, RuntimeError, Type, Value};
+use std::cell::RefCell;
+use std::io::{self, Write};
+use std::rc::Rc;
+use std::thread;
+use std::time::Duration;
+
+#[test]
+fn parse_gives_back_every_syntax_error() {
+    let errors = auctan::parse("x = ;\nprint 1;\ny = 1 +;\n").unwrap_err();
+
+    assert_eq!(errors.len(), 2);
+    assert!(errors
+        .iter()
+        .all(|error| error.message == "expected an expression after `=`"
+            || error.message == "expected an expression after `+`"));
+}
+
+#[test]
+fn syntax_errors_at_the_end_of_long_programs_are_described() {
+    let mut code = "x = 0;\nif (x > 1) {\n    x = 1;\n}\n".repeat(500);
+    code += "if (x > 1) {\n    x = \"a;b{\";\n} else ;\nprint ;\n";
+
+    let errors = auctan::parse(&code).unwrap_err();
+    let messages: Vec<&str> = errors.iter().map(|error| error.message.as_str()).collect();
+    assert_eq!(
+        messages,
+        [
+            "expected `{` after `else`",
+            "expected an expression after `print`"
+        ]
+    );
+}
+
+#[test]
+fn definitions_are_kept_between_runs() {
+    let mut interpreter = Interpreter::new();
+    interpreter
+        .run(&auctan::parse("total = 1;\nfn double(x) {\n    return x * 2;\n}\n").unwrap())
+        .unwrap();
+
+    interpreter
+        .run(&auctan::parse("total = double(total);").unwrap())
+        .unwrap();
+
+    let error = interpreter
+        .run(&auctan::parse("\n\ntotal = double(total, 1);").unwrap())
+        .unwrap_err();
+    assert_eq!(error.kind, ErrorKind::Arity);
+    assert_eq!((error.location.line, error.location.column), (3, 9));
+}
+
+#[test]
+fn errors_in_procedures_from_earlier_runs_point_into_their_own_source() {
+    let mut interpreter = Interpreter::new();
+    interpreter
+        .run(&auctan::parse("proc fail {\n    print missing;\n}\n").unwrap())
+        .unwrap();
+
+    let error = interpreter
+        .run(&auctan::parse("call fail;").unwrap())
+        .unwrap_err();
+    assert_eq!(error.kind, ErrorKind::Undefined);
+    assert_eq!(error.location.source_line, "    print missing;");
+    assert_eq!(error.call_stack[0].location.source_line, "call fail;");
+    assert_eq!(
+        error.render(false),
+        "error: `missing` is not defined.\n \
+         --> line 2, column 11\n  \
+         |\n\
+         2 |     print missing;\n  \
+         |           ^^^^^^^ not defined\n  \
+         = note: `fail` was called from line 1, column 1"
+    );
+}
+
+#[test]
+fn scripts_can_call_registered_functions() {
+    let recorded = Rc::new(RefCell::new(vec![]));
+
+    let mut interpreter = Interpreter::new();
+    interpreter.register_fn("double", &[Type::Num], |args| match args {
+        [Value::Num(num)] => Ok(Value::Num(num * 2.0)),
+        _ => unreachable!(),
+    });
+    let sink = Rc::clone(&recorded);
+    interpreter.register_fn("record", &[Type::Any], move |args| {
+        sink.borrow_mut().push(args[0].clone());
+        Ok(Value::None)
+    });
+
+    let program = auctan::parse("call record(double(4) + 1);\ncall record(\"done\");").unwrap();
+    interpreter.run(&program).unwrap();
+
+    assert_eq!(
+        *recorded.borrow(),
+        [Value::Num(9.0), Value::Str("done".to_string())]
+    );
+}
+
+#[test]
+fn calls_to_registered_functions_are_checked() {
+    let mut interpreter = Interpreter::new();
+    interpreter.register_fn("double", &[Type::Num], |args| match args {
+        [Value::Num(num)] => Ok(Value::Num(num * 2.0)),
+        _ => unreachable!(),
+    });
+
+    let error = interpreter
+        .run(&auctan::parse("x = double(1, 2);").unwrap())
+        .unwrap_err();
+    assert_eq!(error.kind, ErrorKind::Arity);
+
+    let error = interpreter
+        .run(&auctan::parse("x = double(\"two\");").unwrap())
+        .unwrap_err();
+    assert_eq!(error.kind, ErrorKind::Type);
+    assert_eq!(
+        error.message,
+        "`double` expects a number as argument 1, but `\"two\"` is a string."
+    );
+    assert_eq!(error.location.column, 12);
+}
+
+#[test]
+fn errors_from_registered_functions_point_at_the_call() {
+    let mut interpreter = Interpreter::new();
+    interpreter.register_fn("fail", &[], |_| {
+        Err(RuntimeError::new(
+            ErrorKind::Native,
+            "The service is not available.".to_string(),
+        ))
+    });
+
+    let program = auctan::parse("proc check {\n    x = 1 + fail();\n}\ncall check;").unwrap();
+    let error = interpreter.run(&program).unwrap_err();
+
+    assert_eq!(error.kind, ErrorKind::Native);
+    assert_eq!((error.location.line, error.location.column), (2, 13));
+    assert_eq!(error.call_stack[0].name, "check");
+}
+
+#[test]
+fn output_goes_to_the_given_writer() {
+    let program = auctan::parse("print \"a\\tb\\n\";\nprint 1.5;\nprint 2 > 1;").unwrap();
+
+    let mut output = vec![];
+    Interpreter::with_io(Options::default(), &mut output, &b""[..])
+        .run(&program)
+        .unwrap();
+
+    assert_eq!(output, b"a\tb\n1.5true");
+}
+
+/// Fails every write, like a closed pipe
+struct BrokenPipe;
+
+impl Write for BrokenPipe {
+    fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+        Err(io::Error::new(io::ErrorKind::BrokenPipe, "broken pipe"))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn failed_writes_stop_the_program() {
+    let program = auctan::parse("x = 1;\nprint x;").unwrap();
+
+    let error = Interpreter::with_io(Options::default(), BrokenPipe, &b""[..])
+        .run(&program)
+        .unwrap_err();
+
+    assert_eq!(error.kind, ErrorKind::Io);
+    assert_eq!(error.location.line, 2);
+}
+
+#[test]
+fn state_dumps_are_sorted_by_name() {
+    let program = auctan::parse(
+        "total = 3;\nname = \"Ana\";\n\nfn square(x) {\n    return x * x;\n}\nproc greet {\n}\n",
+    )
+    .unwrap();
+    let mut interpreter = Interpreter::with_io(Options::default(), io::sink(), &b""[..]);
+    interpreter.run(&program).unwrap();
+
+    assert_eq!(
+        interpreter.dump_state(DumpFormat::Pretty),
+        "Variables:\n    \
+         name  : string = \"Ana\"\n    \
+         total : number = 3\n\
+         \n\
+         Procedures:\n    \
+         proc greet() at line 7, column 1\n    \
+         fn square(x) at line 4, column 1"
+    );
+    assert_eq!(
+        interpreter.dump_state(DumpFormat::Json),
+        "{\"variables\":[\
+         {\"name\":\"name\",\"type\":\"string\",\"value\":\"Ana\"},\
+         {\"name\":\"total\",\"type\":\"number\",\"value\":3}],\
+         \"procedures\":[\
+         {\"name\":\"greet\",\"kind\":\"proc\",\"params\":[],\"line\":7,\"column\":1},\
+         {\"name\":\"square\",\"kind\":\"fn\",\"params\":[\"x\"],\"line\":4,\"column\":1}]}"
+    );
+}
+
+#[test]
+fn programs_stop_after_the_maximum_number_of_steps() {
+    let options = Options {
+        max_steps: Some(100),
+        ..Options::default()
+    };
+    let program = auctan::parse("x = 0;\nwhile (true) {\n    x = x + 1;\n}").unwrap();
+    let mut interpreter = Interpreter::with_io(options, io::sink(), &b""[..]);
+
+    let error = interpreter.run(&program).unwrap_err();
+    assert_eq!(error.kind, ErrorKind::LimitExceeded(Limit::Steps));
+    assert_eq!(error.message, "The program took more than 100 steps.");
+
+    // Every run gets the full number of steps again, and the globals are still there
+    interpreter
+        .run(
+            &auctan::parse(
+                "proc count {\n    for i in 0..10 {\n        x = x + 1;\n    }\n}\ncall count;",
+            )
+            .unwrap(),
+        )
+        .unwrap();
+    assert!(interpreter
+        .dump_state(DumpFormat::Pretty)
+        .contains("x : number"));
+}
+
+#[test]
+fn programs_stop_when_their_strings_get_too_big() {
+    let options = Options {
+        max_memory: Some(1000),
+        ..Options::default()
+    };
+    let program =
+        auctan::parse("text = \"ab\";\nwhile (true) {\n    text = text + text;\n}").unwrap();
+
+    let error = Interpreter::with_io(options, io::sink(), &b""[..])
+        .run(&program)
+        .unwrap_err();
+    assert_eq!(error.kind, ErrorKind::LimitExceeded(Limit::Memory));
+    assert_eq!((error.location.line, error.location.column), (3, 12));
+}
+
+#[test]
+fn every_copy_of_a_string_counts_against_the_memory_limit() {
+    let options = Options {
+        max_memory: Some(1000),
+        ..Options::default()
+    };
+    let mut code = "s = \"\";\nfor i in 0..40 {\n    s = s + \"0123456789\";\n}\n".to_string();
+    for copy in 0..10 {
+        code += &format!("v{} = s;\n", copy);
+    }
+
+    let error = Interpreter::with_io(options.clone(), io::sink(), &b""[..])
+        .run(&auctan::parse(&code).unwrap())
+        .unwrap_err();
+    assert_eq!(error.kind, ErrorKind::LimitExceeded(Limit::Memory));
+    assert_eq!(error.location.source_line, "v1 = s;");
+
+    // Parameters are copies too
+    let code = "s = \"\";\nfor i in 0..40 {\n    s = s + \"0123456789\";\n}\n\
+                fn keep(text, depth) {\n    if (depth == 0) {\n        return 0;\n    }\n    return keep(text, depth - 1);\n}\n\
+                x = keep(s, 10);";
+    let error = Interpreter::with_io(options, io::sink(), &b""[..])
+        .run(&auctan::parse(code).unwrap())
+        .unwrap_err();
+    assert_eq!(error.kind, ErrorKind::LimitExceeded(Limit::Memory));
+}
+
+#[test]
+fn strings_that_are_replaced_stop_counting() {
+    let options = Options {
+        max_memory: Some(20),
+        ..Options::default()
+    };
+    let program = auctan::parse(
+        "for i in 0..100 {\n    let text = \"abc\" + \"def\";\n    other = text + \"\";\n}",
+    )
+    .unwrap();
+
+    Interpreter::with_io(options, io::sink(), &b""[..])
+        .run(&program)
+        .unwrap();
+}
+
+#[test]
+fn programs_stop_once_they_run_out_of_time() {
+    let options = Options {
+        timeout: Some(Duration::from_millis(50)),
+        ..Options::default()
+    };
+    let program = auctan::parse("while (true) {\n}").unwrap();
+
+    let error = Interpreter::with_io(options, io::sink(), &b""[..])
+        .run(&program)
+        .unwrap_err();
+    assert_eq!(error.kind, ErrorKind::LimitExceeded(Limit::Time));
+}
+
+#[test]
+fn programs_can_be_cancelled_from_another_thread() {
+    let program = auctan::parse("x = 0;\nwhile (true) {\n    x = x + 1;\n}").unwrap();
+    let mut interpreter = Interpreter::with_io(Options::default(), io::sink(), &b""[..]);
+
+    let token = interpreter.cancel_token();
+    let canceller = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(50));
+        token.cancel();
+    });
+
+    let error = interpreter.run(&program).unwrap_err();
+    canceller.join().unwrap();
+    assert_eq!(error.kind, ErrorKind::Cancelled);
+    assert!((2..=3).contains(&error.location.line));
+
+    // Stopping only ends the run that was going on
+    interpreter.run(&auctan::parse("y = x;").unwrap()).unwrap();
+}
+
+#[test]
+fn cancelling_before_a_run_stops_it_right_away() {
+    let mut output = vec![];
+    let mut interpreter = Interpreter::with_io(Options::default(), &mut output, &b""[..]);
+
+    interpreter.cancel_token().cancel();
+    let error = interpreter
+        .run(&auctan::parse("print 1;").unwrap())
+        .unwrap_err();
+    drop(interpreter);
+
+    assert_eq!(error.kind, ErrorKind::Cancelled);
+    assert_eq!(error.location.column, 1);
+    assert!(output.is_empty());
+}
+
+#[test]
+fn deep_recursion_stops_before_the_stack_overflows() {
+    // Tests run on threads with the standard 2 MB of stack
+    for code in [
+        "proc f {\n    call f;\n}\ncall f;",
+        "fn f(n) {\n    if (n > 0 && (n < 0 || true)) {\n        return 1 + (2 * (3 + f(n + 1)));\n    }\n    return 0;\n}\nprint f(1);",
+    ] {
+        let error = Interpreter::with_io(Options::default(), io::sink(), &b""[..])
+            .run(&auctan::parse(code).unwrap())
+            .unwrap_err();
+        assert_eq!(error.kind, ErrorKind::RecursionLimit);
+    }
+}
+
+#[test]
+fn strict_conditions_only_accept_booleans() {
+    let program = auctan::parse("x = 2;\nwhile (x) {\n    x = x - 1;\n}").unwrap();
+
+    Interpreter::with_io(Options::default(), io::sink(), &b""[..])
+        .run(&program)
+        .unwrap();
+
+    let strict = Options {
+        strict_conditions: true,
+        ..Options::default()
+    };
+    let error = Interpreter::with_io(strict.clone(), io::sink(), &b""[..])
+        .run(&program)
+        .unwrap_err();
+    assert_eq!(error.kind, ErrorKind::Type);
+    assert_eq!(
+        error.message,
+        "`2` is a number, but conditions have to be `true` or `false` in strict mode."
+    );
+    assert_eq!((error.location.line, error.location.column), (2, 8));
+
+    let program = auctan::parse("x = 2;\nwhile (x != 0) {\n    x = x - 1;\n}").unwrap();
+    Interpreter::with_io(strict, io::sink(), &b""[..])
+        .run(&program)
+        .unwrap();
+}
+
+#[test]
+fn deeply_nested_code_stops_before_the_stack_overflows() {
+    let negations = |depth: usize| format!("print {}1;", "- ".repeat(depth));
+    let ifs = |depth: usize| {
+        format!(
+            "print \"started\";\nproc p {{\n{}print 1;{}\n}}\ncall p;",
+            "if (true) {\n".repeat(depth),
+            "\n}".repeat(depth)
+        )
+    };
+
+    for code in [negations(20_000), ifs(20_000)] {
+        let errors = auctan::parse(&code).unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            errors.iter().next().unwrap().message,
+            "code is nested more than 500 levels deep"
+        );
+    }
+
+    // Code just inside the limit either runs or stops with an error, it never crashes
+    for code in [negations(490), ifs(240)] {
+        let program = auctan::parse(&code).unwrap();
+        let result = Interpreter::with_io(Options::default(), io::sink(), &b""[..]).run(&program);
+        if let Err(error) = result {
+            assert_eq!(error.kind, ErrorKind::RecursionLimit);
+        }
+    }
+}
+
+#[test]
+fn programs_give_back_what_was_parsed() {
+    let program = auctan::parse("x = 1;\nprint x;").unwrap();
+
+    assert_eq!(program.source(), "x = 1;\nprint x;");
+    assert_eq!(program.statements().len(), 2);
+    assert_eq!(program.statements()[1].span.start, 7);
+}
+
+#[test]
+fn timeouts_too_long_for_the_clock_never_run_out() {
+    let options = Options {
+        timeout: Some(Duration::MAX),
+        ..Options::default()
+    };
+    let program = auctan::parse("for i in 0..1000 {\n}").unwrap();
+
+    Interpreter::with_io(options, io::sink(), &b""[..])
+        .run(&program)
+        .unwrap();
+}
@@ -0,0 +1,105 @@
+use auctan::{Interpreter, Options};
+
+/// Runs the script and gives back exactly what it printed, or the error it stopped on
+fn run(code: &str) -> String {
+    let program = auctan::parse(code).unwrap();
+
+    let mut output = vec![];
+    let result = Interpreter::with_io(Options::default(), &mut output, &b""[..]).run(&program);
+    let mut printed = String::from_utf8(output).unwrap();
+    if let Err(error) = result {
+        printed += &error.message;
+    }
+
+    printed
+}
+
+#[test]
+fn for_leaves_out_the_end_of_the_range() {
+    assert_eq!(run("for i in 0..4 {\n    print i;\n}"), "0123");
+    assert_eq!(run("for i in 3..3 {\n    print i;\n}"), "");
+}
+
+#[test]
+fn for_includes_the_end_with_dot_dot_equals() {
+    assert_eq!(run("for i in 0..=4 {\n    print i;\n}"), "01234");
+    assert_eq!(run("for i in 3..=3 {\n    print i;\n}"), "3");
+}
+
+#[test]
+fn for_counts_with_the_step() {
+    assert_eq!(run("for i in 0..10 step 3 {\n    print i;\n}"), "0369");
+    assert_eq!(run("for i in 0..=9 step 3 {\n    print i;\n}"), "0369");
+    assert_eq!(
+        run("for i in 0..1 step 0.25 {\n    print i;\n}"),
+        "00.250.50.75"
+    );
+}
+
+#[test]
+fn negative_steps_count_down() {
+    assert_eq!(run("for i in 3..0 step -1 {\n    print i;\n}"), "321");
+    assert_eq!(run("for i in 3..=0 step -1 {\n    print i;\n}"), "3210");
+    // Counting up with a negative step never starts
+    assert_eq!(run("for i in 0..3 step -1 {\n    print i;\n}"), "");
+}
+
+#[test]
+fn the_range_and_step_are_read_once() {
+    assert_eq!(
+        run("end = 3;\nfor i in 0..end {\n    end = 10;\n    print i;\n}"),
+        "012"
+    );
+}
+
+#[test]
+fn a_step_of_zero_is_an_error() {
+    assert_eq!(
+        run("print \"a\";\nfor i in 0..3 step 0 {\n    print i;\n}"),
+        "aThe step of a `for` loop cannot be 0."
+    );
+}
+
+#[test]
+fn break_only_leaves_the_innermost_loop() {
+    assert_eq!(
+        run("for i in 0..3 {\n    for j in 0..3 {\n        if (j == 1) {\n            break;\n        }\n        print j;\n    }\n    print i;\n}"),
+        "000102"
+    );
+    assert_eq!(
+        run("i = 0;\nwhile (i < 2) {\n    i = i + 1;\n    while (true) {\n        break;\n    }\n    print i;\n}"),
+        "12"
+    );
+}
+
+#[test]
+fn continue_only_skips_in_the_innermost_loop() {
+    assert_eq!(
+        run("for i in 0..2 {\n    for j in 0..3 {\n        if (j == 1) {\n            continue;\n        }\n        print j;\n    }\n    print \"|\";\n}"),
+        "02|02|"
+    );
+}
+
+#[test]
+fn break_and_continue_work_from_inside_if_blocks() {
+    assert_eq!(
+        run("i = 0;\nwhile (true) {\n    i = i + 1;\n    if (i == 2) {\n        continue;\n    }\n    if (i > 4) {\n        if (true) {\n            break;\n        }\n    }\n    print i;\n}"),
+        "134"
+    );
+    assert_eq!(
+        run("for i in 0..10 {\n    if (i % 2 == 0) {\n        continue;\n    } else {\n        if (i > 6) {\n            break;\n        }\n    }\n    print i;\n}"),
+        "135"
+    );
+}
+
+#[test]
+fn return_inside_a_loop_leaves_the_function() {
+    assert_eq!(
+        run("fn first_over(limit) {\n    for i in 0..100 {\n        while (true) {\n            if (i * i > limit) {\n                return i;\n            }\n            break;\n        }\n    }\n    return -1;\n}\nprint first_over(10);\nprint first_over(100000);"),
+        "4-1"
+    );
+    assert_eq!(
+        run("proc count {\n    i = 0;\n    while (true) {\n        i = i + 1;\n        if (i == 3) {\n            return;\n        }\n        print i;\n    }\n}\ncall count;\nprint \"done\";"),
+        "12done"
+    );
+}
@@ -0,0 +1,75 @@
+use auctan::{Interpreter, Options};
+
+/// Runs the script and gives back exactly what it printed, or the error it stopped on
+fn run(code: &str) -> String {
+    let program = auctan::parse(code).unwrap();
+
+    let mut output = vec![];
+    let result = Interpreter::with_io(Options::default(), &mut output, &b""[..]).run(&program);
+    let mut printed = String::from_utf8(output).unwrap();
+    if let Err(error) = result {
+        printed += &error.message;
+    }
+
+    printed
+}
+
+#[test]
+fn let_only_shadows_inside_its_block() {
+    assert_eq!(
+        run("name = \"global\";\nif (true) {\n    let name = \"block\";\n    print name;\n}\nprint name;"),
+        "blockglobal"
+    );
+}
+
+#[test]
+fn assignment_updates_an_outer_variable() {
+    assert_eq!(
+        run("count = 0;\nif (true) {\n    count = count + 1;\n}\nprint count;"),
+        "1"
+    );
+}
+
+#[test]
+fn variables_created_in_a_block_do_not_leak_out() {
+    assert_eq!(
+        run("if (true) {\n    temporary = 1;\n}\nprint temporary;"),
+        "`temporary` is not defined."
+    );
+    assert_eq!(
+        run("if (true) {\n    r = 1;\n} else {\n    r = 2;\n}\nprint r;"),
+        "`r` is not defined."
+    );
+    assert_eq!(
+        run("for i in 0..3 {\n    print i;\n}\nprint i;"),
+        "012`i` is not defined."
+    );
+}
+
+#[test]
+fn procedures_do_not_see_the_callers_locals() {
+    assert_eq!(
+        run("name = \"global\";\nfn read() {\n    return name;\n}\nif (true) {\n    let name = \"caller\";\n    print read();\n}"),
+        "global"
+    );
+    assert_eq!(
+        run("proc read {\n    print local;\n}\nproc caller {\n    local = 1;\n    call read;\n}\ncall caller;"),
+        "`local` is not defined."
+    );
+}
+
+#[test]
+fn procedures_can_update_globals() {
+    assert_eq!(
+        run("count = 0;\nproc increment {\n    count = count + 1;\n}\ncall increment;\ncall increment;\nprint count;"),
+        "2"
+    );
+}
+
+#[test]
+fn parameters_shadow_globals() {
+    assert_eq!(
+        run("name = \"global\";\nproc shadow(name) {\n    name = name + \"!\";\n    print name;\n}\ncall shadow(\"parameter\");\nprint name;"),
+        "parameter!global"
+    );
+}